@@ -171,6 +171,21 @@ impl Editor {
             DeferredAction::OpenConfigFile { layer } => {
                 self.open_config_file(layer)?;
             }
+            DeferredAction::CheckForUpdates => {
+                self.check_for_updates_from_settings();
+            }
+            DeferredAction::MarkCustomizationsSeen => {
+                self.mark_settings_customizations_seen();
+            }
+            DeferredAction::SaveStarredSettings => {
+                self.save_starred_settings();
+            }
+            DeferredAction::PreviewSettings => {
+                self.preview_settings();
+            }
+            DeferredAction::EndSettingsPreview { keep } => {
+                self.end_settings_preview(keep);
+            }
 
             // Menu actions
             DeferredAction::CloseMenu => {
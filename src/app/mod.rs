@@ -484,6 +484,12 @@ pub struct Editor {
     /// Periodic update checker (checks for new releases every hour)
     update_checker: Option<crate::services::release_checker::PeriodicUpdateChecker>,
 
+    /// URL queried by on-demand update checks
+    releases_url: String,
+
+    /// Update check started from the settings modal, if still running
+    settings_update_check: Option<crate::services::release_checker::UpdateCheckHandle>,
+
     /// Terminal manager for built-in terminal support
     terminal_manager: crate::services::terminal::TerminalManager,
 
@@ -519,6 +525,13 @@ pub struct Editor {
     /// Settings UI state (when settings modal is open)
     pub(crate) settings_state: Option<crate::view::settings::SettingsState>,
 
+    /// When settings were last saved from the settings modal
+    settings_last_saved: Option<std::time::Instant>,
+
+    /// Config in effect before the settings modal's pending changes were
+    /// applied for preview, restored when the preview is reverted
+    settings_preview: Option<Config>,
+
     /// Terminal color capability (true color, 256, or 16 colors)
     color_capability: crate::view::color_support::ColorCapability,
 
@@ -948,6 +961,8 @@ impl Editor {
             warning_log: None,
             warning_domains: WarningDomainRegistry::new(),
             update_checker,
+            releases_url: crate::services::release_checker::DEFAULT_RELEASES_URL.to_string(),
+            settings_update_check: None,
             terminal_manager: crate::services::terminal::TerminalManager::new(),
             terminal_buffers: HashMap::new(),
             terminal_backing_files: HashMap::new(),
@@ -958,6 +973,8 @@ impl Editor {
             previous_click_time: None,
             previous_click_position: None,
             settings_state: None,
+            settings_last_saved: None,
+            settings_preview: None,
             color_capability,
             stdin_streaming: None,
             review_hunks: Vec::new(),
@@ -1144,6 +1161,12 @@ impl Editor {
         self.update_checker = Some(checker);
     }
 
+    /// Set the URL used by on-demand update checks (for testing)
+    #[doc(hidden)]
+    pub fn set_releases_url(&mut self, url: impl Into<String>) {
+        self.releases_url = url.into();
+    }
+
     /// Configure LSP server for a specific language
     pub fn set_lsp_config(&mut self, language: String, config: LspServerConfig) {
         if let Some(ref mut lsp) = self.lsp {
@@ -2246,6 +2269,20 @@ impl Editor {
         self.settings_state.as_ref().is_some_and(|s| s.visible)
     }
 
+    /// Get the layout of the settings dialog from the last render, if it is open
+    pub fn settings_layout(&self) -> Option<&crate::view::settings::SettingsLayout> {
+        if self.is_settings_open() {
+            self.cached_layout.settings_layout.as_ref()
+        } else {
+            None
+        }
+    }
+
+    /// Get the settings dialog state, if it is open
+    pub fn settings_state(&self) -> Option<&crate::view::settings::SettingsState> {
+        self.settings_state.as_ref().filter(|s| s.visible)
+    }
+
     /// Request the editor to quit
     pub fn quit(&mut self) {
         // Check for unsaved buffers
@@ -3179,13 +3216,24 @@ impl Editor {
             // Poll for results but don't act on them - just cache
             let _ = checker.poll_result();
         }
+        let settings_update_checked = self.poll_settings_update_check();
+        let settings_notification_expired = self
+            .settings_state
+            .as_mut()
+            .is_some_and(|state| state.clear_expired_notification());
 
         // Poll for file changes (auto-revert) and file tree changes
         let file_changes = self.poll_file_changes();
         let tree_changes = self.poll_file_tree_changes();
 
         // Trigger render if any async messages, plugin commands were processed, or plugin requested render
-        needs_render || processed_any_commands || plugin_render || file_changes || tree_changes
+        needs_render
+            || processed_any_commands
+            || plugin_render
+            || file_changes
+            || tree_changes
+            || settings_update_checked
+            || settings_notification_expired
     }
 
     /// Update LSP status bar string from active progress operations
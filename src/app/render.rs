@@ -727,13 +727,14 @@ impl Editor {
 
         // Render settings modal (before menu bar so menus can overlay)
         // Check visibility first to avoid borrow conflict with dimming
-        let settings_visible = self
+        let dim_editor = self
             .settings_state
             .as_ref()
-            .map(|s| s.visible)
+            .map(|s| s.visible && !s.previewing)
             .unwrap_or(false);
-        if settings_visible {
-            // Dim the editor content behind the settings modal
+        if dim_editor {
+            // Dim the editor content behind the settings modal, except while
+            // previewing changes so the editor shows them at full strength
             crate::view::dimming::apply_dimming(frame, size);
         }
         if let Some(ref mut settings_state) = self.settings_state {
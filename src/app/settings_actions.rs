@@ -7,8 +7,11 @@
 //! - Activating/toggling settings
 //! - Incrementing/decrementing numeric values
 
-use crate::config_io::{ConfigLayer, ConfigResolver};
+use crate::config_io::{AuditEntry, ConfigLayer, ConfigResolver};
 use crate::input::keybindings::KeybindingResolver;
+use crate::services::release_checker;
+use crate::state::ViewMode;
+use crate::view::settings::NotificationKind;
 
 use super::Editor;
 
@@ -28,8 +31,58 @@ impl Editor {
                     if let Ok(sources) = resolver.get_layer_sources() {
                         state.set_layer_sources(sources);
                     }
+                    state.set_inherited_configs(
+                        [
+                            ConfigLayer::User,
+                            ConfigLayer::Project,
+                            ConfigLayer::Session,
+                        ]
+                        .into_iter()
+                        .filter_map(|layer| {
+                            let config = resolver.inherited_config(layer).ok()?;
+                            Some((layer, serde_json::to_value(config).ok()?))
+                        })
+                        .collect(),
+                    );
+                    state.set_layer_configs(
+                        [
+                            (ConfigLayer::User, resolver.load_user_layer()),
+                            (ConfigLayer::Project, resolver.load_project_layer()),
+                            (ConfigLayer::Session, resolver.load_session_layer()),
+                        ]
+                        .into_iter()
+                        .filter_map(|(layer, partial)| {
+                            Some((layer, serde_json::to_value(partial.ok()??).ok()?))
+                        })
+                        .collect(),
+                    );
+                    state.set_config_paths(
+                        [
+                            (ConfigLayer::User, resolver.user_config_path()),
+                            (ConfigLayer::Project, resolver.project_config_write_path()),
+                            (ConfigLayer::Session, resolver.session_config_path()),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    );
+                    state.set_working_dir(self.working_dir.clone());
+                    state.set_time_source(self.time_source.clone(), self.settings_last_saved);
                     state.show();
+                    // First settings open: point out what the config files
+                    // already customize. It counts as seen once dismissed, or
+                    // right away when there is nothing to point out.
+                    let first_open = !self
+                        .dir_context
+                        .settings_customizations_seen_path()
+                        .exists();
+                    if first_open {
+                        state.show_customizations();
+                    }
+                    let seen = first_open && !state.showing_customizations;
                     self.settings_state = Some(state);
+                    if seen {
+                        self.mark_settings_customizations_seen();
+                    }
                 }
                 Err(e) => {
                     self.set_status_message(format!("Failed to open settings: {}", e));
@@ -45,6 +98,7 @@ impl Editor {
     ///
     /// If `save` is true and there are changes, they will be applied first.
     pub fn close_settings(&mut self, save: bool) {
+        self.end_settings_preview(false);
         if save {
             self.save_settings();
         }
@@ -59,17 +113,39 @@ impl Editor {
 
     /// Save the settings from the modal to config
     pub fn save_settings(&mut self) {
-        let old_theme = self.config.theme.clone();
-        let old_locale = self.config.locale.clone();
+        // Save over the settings the preview replaced
+        self.end_settings_preview(false);
 
-        // Get target layer and new config
-        let (target_layer, new_config) = {
+        // Get target layer, new config and the changes being applied
+        let (target_layer, new_config, audit_entries, explicit_values) = {
             if let Some(ref state) = self.settings_state {
                 if !state.has_changes() {
                     return;
                 }
+                let timestamp =
+                    chrono::DateTime::<chrono::Local>::from(self.time_source.system_now())
+                        .to_rfc3339();
+                let mut audit_entries: Vec<AuditEntry> = state
+                    .pending_changes
+                    .iter()
+                    .map(|(path, new)| AuditEntry {
+                        timestamp: timestamp.clone(),
+                        path: path.clone(),
+                        old: state
+                            .original_value(path)
+                            .cloned()
+                            .unwrap_or(serde_json::Value::Null),
+                        new: new.clone(),
+                    })
+                    .collect();
+                audit_entries.sort_by(|a, b| a.path.cmp(&b.path));
                 match state.apply_changes(&self.config) {
-                    Ok(config) => (state.target_layer, config),
+                    Ok(config) => (
+                        state.target_layer,
+                        config,
+                        audit_entries,
+                        state.explicit_inherited_values(),
+                    ),
                     Err(e) => {
                         self.set_status_message(format!("Failed to apply settings: {}", e));
                         return;
@@ -81,16 +157,115 @@ impl Editor {
         };
 
         // Apply the new config
-        self.config = new_config.clone();
+        let old_config = std::mem::replace(&mut self.config, new_config.clone());
+        self.apply_settings_runtime(&old_config);
+
+        // Save to disk using the appropriate layer
+        let resolver = ConfigResolver::new(self.dir_context.clone(), self.working_dir.clone());
+
+        let layer_name = match target_layer {
+            ConfigLayer::User => "User",
+            ConfigLayer::Project => "Project",
+            ConfigLayer::Session => "Session",
+            ConfigLayer::System => "System", // Should never happen
+        };
+
+        // Toggles set to the value they would inherit anyway are left out of
+        // the layer's delta, so write them back to keep them explicit
+        let saved = resolver
+            .save_to_layer(&new_config, target_layer)
+            .and_then(|()| resolver.set_layer_values(target_layer, &explicit_values));
+        match saved {
+            Ok(()) => {
+                let audit_path = self.dir_context.settings_audit_log_path();
+                if let Err(e) = crate::config_io::append_audit_entries(&audit_path, &audit_entries)
+                {
+                    tracing::warn!("Failed to write settings audit log: {}", e);
+                }
+                // Settings seen in this version are no longer new. This is
+                // per user, so it goes to the User layer whatever the target.
+                let version = env!("CARGO_PKG_VERSION");
+                self.config.settings_last_seen_version = Some(version.to_string());
+                if let Err(e) = resolver
+                    .set_user_value("settings_last_seen_version", serde_json::json!(version))
+                {
+                    tracing::warn!("Failed to record the settings version: {}", e);
+                }
+                self.set_status_message(format!("Settings saved to {} layer", layer_name));
+                self.settings_last_saved = Some(self.time_source.now());
+                // Clear settings state entirely so next open creates fresh state
+                // from the updated config. This fixes issue #474 where reopening
+                // settings after save would show stale values.
+                self.settings_state = None;
+            }
+            Err(e) => {
+                self.set_status_message(format!("Failed to save settings: {}", e));
+            }
+        }
+    }
+
+    /// Apply the pending settings changes to the live UI without saving
+    /// them, so the theme, layout and dialog size can be seen before
+    /// committing.
+    /// The preview lasts until it is kept or reverted.
+    pub fn preview_settings(&mut self) {
+        let Some(ref mut state) = self.settings_state else {
+            return;
+        };
+        if self.settings_preview.is_some() || !state.has_changes() {
+            return;
+        }
+        let config = match state.apply_changes(&self.config) {
+            Ok(config) => config,
+            Err(e) => {
+                state.notify(
+                    format!("Failed to apply settings: {}", e),
+                    NotificationKind::Error,
+                );
+                return;
+            }
+        };
+        state.previewing = true;
+        state.modal_size_percent = (
+            config.editor.settings_width_percent,
+            config.editor.settings_height_percent,
+        );
+        let previous = std::mem::replace(&mut self.config, config);
+        self.apply_settings_runtime(&previous);
+        self.settings_preview = Some(previous);
+    }
+
+    /// End the settings preview. The previous settings are restored either
+    /// way; kept changes are then saved like any other.
+    pub fn end_settings_preview(&mut self, keep: bool) {
+        let Some(previous) = self.settings_preview.take() else {
+            return;
+        };
+        if let Some(ref mut state) = self.settings_state {
+            state.previewing = false;
+            state.modal_size_percent = (
+                previous.editor.settings_width_percent,
+                previous.editor.settings_height_percent,
+            );
+        }
+        let previewed = std::mem::replace(&mut self.config, previous);
+        self.apply_settings_runtime(&previewed);
+        if keep {
+            self.close_settings(true);
+        }
+    }
 
-        // Apply runtime changes
-        if old_theme != self.config.theme {
+    /// Bring the live UI in line with `self.config` after it replaced
+    /// `old`: theme, locale, keybindings, and line numbers and wrapping in
+    /// open views that were following the old defaults
+    fn apply_settings_runtime(&mut self, old: &crate::config::Config) {
+        if old.theme != self.config.theme {
             self.theme = crate::view::theme::Theme::from_name(&self.config.theme);
             tracing::info!("Theme changed to '{}'", self.config.theme.0);
         }
 
         // Apply locale change at runtime
-        if old_locale != self.config.locale {
+        if old.locale != self.config.locale {
             if let Some(locale) = self.config.locale.as_option() {
                 crate::i18n::set_locale(locale);
                 // Regenerate menus with the new locale
@@ -107,28 +282,106 @@ impl Editor {
         // Update keybindings
         self.keybindings = KeybindingResolver::new(&self.config);
 
-        // Save to disk using the appropriate layer
-        let resolver = ConfigResolver::new(self.dir_context.clone(), self.working_dir.clone());
+        // File buffers showing the old default switch to the new one;
+        // terminals, virtual buffers, compose mode and buffers toggled by
+        // hand keep their own
+        let line_numbers = self.config.editor.line_numbers;
+        if old.editor.line_numbers != line_numbers {
+            for (id, state) in self.buffers.iter_mut() {
+                let follows_default = state.margins.show_line_numbers == old.editor.line_numbers
+                    && state.view_mode != ViewMode::Compose
+                    && !self.terminal_buffers.contains_key(id)
+                    && !self
+                        .buffer_metadata
+                        .get(id)
+                        .is_some_and(|metadata| metadata.is_virtual());
+                if follows_default {
+                    state.margins.set_line_numbers(line_numbers);
+                    if line_numbers {
+                        let total_lines = state.buffer.line_count().unwrap_or(1);
+                        state.margins.update_width_for_buffer(total_lines);
+                    }
+                }
+            }
+        }
 
-        let layer_name = match target_layer {
-            ConfigLayer::User => "User",
-            ConfigLayer::Project => "Project",
-            ConfigLayer::Session => "Session",
-            ConfigLayer::System => "System", // Should never happen
+        // Wrapping is per split, as with the toggle-line-wrap action
+        let line_wrap = self.config.editor.line_wrap;
+        if old.editor.line_wrap != line_wrap {
+            for view_state in self.split_view_states.values_mut() {
+                if view_state.view_mode != ViewMode::Compose {
+                    view_state.viewport.line_wrap_enabled = line_wrap;
+                }
+            }
+        }
+    }
+
+    /// Remember that the "What Changed" panel was dismissed, so later
+    /// settings opens go straight to the settings
+    pub fn mark_settings_customizations_seen(&mut self) {
+        let path = self.dir_context.settings_customizations_seen_path();
+        let written = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|()| std::fs::write(&path, ""));
+        if let Err(e) = written {
+            tracing::warn!("Failed to write {}: {}", path.display(), e);
+        }
+    }
+
+    /// Save the settings starred in the modal to the user config right away,
+    /// apart from the pending changes
+    pub fn save_starred_settings(&mut self) {
+        let Some(ref state) = self.settings_state else {
+            return;
         };
+        let starred = state.starred_paths();
+        self.config.starred_settings = starred.clone();
+        let resolver = ConfigResolver::new(self.dir_context.clone(), self.working_dir.clone());
+        if let Err(e) = resolver.set_user_value("starred_settings", serde_json::json!(starred)) {
+            self.set_status_message(format!("Failed to save starred settings: {}", e));
+        }
+    }
 
-        match resolver.save_to_layer(&new_config, target_layer) {
-            Ok(()) => {
-                self.set_status_message(format!("Settings saved to {} layer", layer_name));
-                // Clear settings state entirely so next open creates fresh state
-                // from the updated config. This fixes issue #474 where reopening
-                // settings after save would show stale values.
-                self.settings_state = None;
-            }
-            Err(e) => {
-                self.set_status_message(format!("Failed to save settings: {}", e));
-            }
+    /// Get the history of settings changes saved from the Settings UI, oldest first
+    pub fn settings_audit_log(&self) -> Vec<AuditEntry> {
+        crate::config_io::read_audit_log(&self.dir_context.settings_audit_log_path())
+    }
+
+    /// Start a background update check from the settings modal.
+    /// Progress and the result are shown as settings notifications.
+    pub fn check_for_updates_from_settings(&mut self) {
+        if self.settings_update_check.is_some() {
+            return;
+        }
+        self.settings_update_check = Some(release_checker::start_update_check(&self.releases_url));
+        if let Some(ref mut state) = self.settings_state {
+            state.notify("Checking for updates…", NotificationKind::Progress);
+        }
+    }
+
+    /// Report a finished settings update check. Returns true if it finished.
+    pub(super) fn poll_settings_update_check(&mut self) -> bool {
+        let Some(result) = self.settings_update_check.as_ref().and_then(|h| h.poll()) else {
+            return false;
+        };
+        self.settings_update_check = None;
+
+        let (message, kind) = match result {
+            Ok(release) if release.update_available => (
+                format!("Update available: {}", release.latest_version),
+                NotificationKind::Info,
+            ),
+            Ok(_) => ("Up to date".to_string(), NotificationKind::Success),
+            Err(e) => (
+                format!("Update check failed: {}", e),
+                NotificationKind::Error,
+            ),
+        };
+        if let Some(ref mut state) = self.settings_state {
+            state.notify(message, kind);
         }
+        true
     }
 
     /// Open the config file for the specified layer in the editor.
@@ -290,6 +543,7 @@ impl Editor {
                     SettingControl::Map(_) => "map",
                     SettingControl::ObjectArray(_) => "objectarray",
                     SettingControl::Json(_) => "json",
+                    SettingControl::KeyChord(_) => "keychord",
                     SettingControl::Complex { .. } => "complex",
                 })
             } else {
@@ -301,12 +555,7 @@ impl Editor {
         match control_type {
             Some("toggle") => {
                 if let Some(ref mut state) = self.settings_state {
-                    if let Some(item) = state.current_item_mut() {
-                        if let SettingControl::Toggle(ref mut toggle_state) = item.control {
-                            toggle_state.checked = !toggle_state.checked;
-                        }
-                    }
-                    state.on_value_changed();
+                    state.toggle_current();
                 }
             }
             Some("dropdown") => {
@@ -363,6 +612,12 @@ impl Editor {
                     state.start_number_editing();
                 }
             }
+            Some("keychord") => {
+                // For keybindings: wait for the new key
+                if let Some(ref mut state) = self.settings_state {
+                    state.start_key_capture();
+                }
+            }
             _ => {}
         }
     }
@@ -361,15 +361,29 @@ pub struct Config {
 
     /// Per-language configuration overrides (tab size, formatters, etc.)
     #[serde(default)]
+    #[schemars(extend("x-doc-url" = "https://github.com/sinelaw/fresh/blob/master/docs/USER_GUIDE.md#add-a-custom-language"))]
     pub languages: HashMap<String, LanguageConfig>,
 
     /// LSP server configurations by language
     #[serde(default)]
+    #[schemars(extend("x-doc-url" = "https://github.com/sinelaw/fresh/blob/master/docs/USER_GUIDE.md#lsp-integration"))]
     pub lsp: HashMap<String, LspServerConfig>,
 
     /// Warning notification settings
     #[serde(default)]
     pub warnings: WarningsConfig,
+
+    /// Editor version the settings were last saved from. Settings added
+    /// in later versions are badged as new in the Settings dialog.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(skip)]
+    pub settings_last_seen_version: Option<String>,
+
+    /// JSON paths of settings starred in the Settings dialog, listed
+    /// together in its "Starred" category
+    #[serde(default)]
+    #[schemars(skip)]
+    pub starred_settings: Vec<String>,
 }
 
 fn default_keybinding_map_name() -> KeybindingMapName {
@@ -419,6 +433,8 @@ pub struct EditorConfig {
 
     /// Maximum time in milliseconds for syntax highlighting per frame
     #[serde(default = "default_highlight_timeout")]
+    #[schemars(extend("x-advanced" = true))]
+    #[schemars(extend("x-unit" = "ms"))]
     pub highlight_timeout_ms: u64,
 
     /// Undo history snapshot interval (number of edits between snapshots)
@@ -431,12 +447,15 @@ pub struct EditorConfig {
     /// - Use constant-size scrollbar thumb (1 char)
     /// Files smaller will count actual lines for accurate scrollbar rendering
     #[serde(default = "default_large_file_threshold")]
+    #[schemars(extend("x-advanced" = true))]
+    #[schemars(extend("x-unit" = "bytes"))]
     pub large_file_threshold_bytes: u64,
 
     /// Estimated average line length in bytes (used for large file line estimation)
     /// This is used by LineIterator to estimate line positions in large files
     /// without line metadata. Typical values: 80-120 bytes.
     #[serde(default = "default_estimated_line_length")]
+    #[schemars(extend("x-advanced" = true))]
     pub estimated_line_length: usize,
 
     /// Whether to enable LSP inlay hints (type hints, parameter hints, etc.)
@@ -454,6 +473,7 @@ pub struct EditorConfig {
     /// Default: 2 seconds for fast recovery with minimal data loss.
     /// Set to 0 to disable periodic auto-save (manual recovery only).
     #[serde(default = "default_auto_save_interval")]
+    #[schemars(extend("x-unit" = "s"))]
     pub auto_save_interval_secs: u32,
 
     /// Number of bytes to look back/forward from the viewport for syntax highlighting context.
@@ -461,6 +481,8 @@ pub struct EditorConfig {
     /// but may slow down highlighting for very large files.
     /// Default: 10KB (10000 bytes)
     #[serde(default = "default_highlight_context_bytes")]
+    #[schemars(extend("x-advanced" = true))]
+    #[schemars(extend("x-unit" = "bytes"))]
     pub highlight_context_bytes: usize,
 
     /// Whether mouse hover triggers LSP hover requests.
@@ -473,12 +495,14 @@ pub struct EditorConfig {
     /// Lower values show hover info faster but may cause more LSP server load.
     /// Default: 500ms
     #[serde(default = "default_mouse_hover_delay")]
+    #[schemars(extend("x-unit" = "ms"))]
     pub mouse_hover_delay_ms: u64,
 
     /// Time window in milliseconds for detecting double-clicks.
     /// Two clicks within this time are treated as a double-click (word selection).
     /// Default: 500ms
     #[serde(default = "default_double_click_time")]
+    #[schemars(extend("x-unit" = "ms"))]
     pub double_click_time_ms: u64,
 
     /// Poll interval in milliseconds for auto-reverting open buffers.
@@ -486,6 +510,7 @@ pub struct EditorConfig {
     /// Lower values detect external changes faster but use more CPU.
     /// Default: 2000ms (2 seconds)
     #[serde(default = "default_auto_revert_poll_interval")]
+    #[schemars(extend("x-unit" = "ms"))]
     pub auto_revert_poll_interval_ms: u64,
 
     /// Poll interval in milliseconds for refreshing expanded directories in the file explorer.
@@ -493,6 +518,7 @@ pub struct EditorConfig {
     /// Lower values detect changes faster but use more CPU.
     /// Default: 3000ms (3 seconds)
     #[serde(default = "default_file_tree_poll_interval")]
+    #[schemars(extend("x-unit" = "ms"))]
     pub file_tree_poll_interval_ms: u64,
 
     /// Default line ending format for new files.
@@ -507,6 +533,19 @@ pub struct EditorConfig {
     /// Default: blinking_block
     #[serde(default)]
     pub cursor_style: CursorStyle,
+
+    /// Width of the Settings dialog as a percentage of the terminal width (10-100).
+    /// The dialog is never wider than 100 columns.
+    /// Default: 80
+    #[serde(default = "default_settings_width_percent")]
+    #[schemars(range(min = 10, max = 100), extend("x-since" = "0.1.67"))]
+    pub settings_width_percent: u16,
+
+    /// Height of the Settings dialog as a percentage of the terminal height (10-100).
+    /// Default: 90
+    #[serde(default = "default_settings_height_percent")]
+    #[schemars(range(min = 10, max = 100), extend("x-since" = "0.1.67"))]
+    pub settings_height_percent: u16,
 }
 
 fn default_tab_size() -> usize {
@@ -570,6 +609,14 @@ fn default_file_tree_poll_interval() -> u64 {
     3000 // 3 seconds between directory mtime checks
 }
 
+fn default_settings_width_percent() -> u16 {
+    80
+}
+
+fn default_settings_height_percent() -> u16 {
+    90
+}
+
 impl Default for EditorConfig {
     fn default() -> Self {
         Self {
@@ -595,6 +642,8 @@ impl Default for EditorConfig {
             file_tree_poll_interval_ms: default_file_tree_poll_interval(),
             default_line_ending: LineEndingOption::default(),
             cursor_style: CursorStyle::default(),
+            settings_width_percent: default_settings_width_percent(),
+            settings_height_percent: default_settings_height_percent(),
         }
     }
 }
@@ -827,6 +876,7 @@ pub struct LanguageConfig {
     /// Path to custom TextMate grammar file (optional)
     /// If specified, this grammar will be used when highlighter is "textmate"
     #[serde(default)]
+    #[schemars(extend("x-file-path" = true))]
     pub textmate_grammar: Option<std::path::PathBuf>,
 
     /// Whether to show whitespace tab indicators (→) for this language
@@ -1113,6 +1163,8 @@ impl Default for Config {
             languages: Self::default_languages(),
             lsp: Self::default_lsp_config(),
             warnings: WarningsConfig::default(),
+            settings_last_seen_version: None,
+            starred_settings: Vec::new(),
         }
     }
 }
@@ -6,6 +6,7 @@
 
 use crate::config::{Config, ConfigError};
 use crate::partial_config::{Merge, PartialConfig, SessionConfig};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::path::{Path, PathBuf};
 
@@ -85,7 +86,7 @@ fn migrate_v0_to_v1(mut value: Value) -> Result<Value, ConfigError> {
 }
 
 /// Represents a configuration layer in the 4-level hierarchy.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ConfigLayer {
     /// Hardcoded defaults embedded in binary (lowest precedence)
     System,
@@ -303,6 +304,70 @@ impl ConfigResolver {
         Ok(())
     }
 
+    /// Set a single top-level value in the user layer file, keeping the
+    /// rest of the file as it is. For state the editor records on its own,
+    /// outside of an explicit settings save.
+    pub fn set_user_value(&self, key: &str, value: Value) -> Result<(), ConfigError> {
+        self.set_layer_values(ConfigLayer::User, &[(format!("/{}", key), value)])
+    }
+
+    /// Set values at JSON pointer paths in a layer file, keeping the rest
+    /// of the file as it is and creating parent objects as needed.
+    pub fn set_layer_values(
+        &self,
+        layer: ConfigLayer,
+        values: &[(String, Value)],
+    ) -> Result<(), ConfigError> {
+        if values.is_empty() {
+            return Ok(());
+        }
+        let path = match layer {
+            ConfigLayer::User => self.user_config_path(),
+            ConfigLayer::Project => self.project_config_write_path(),
+            ConfigLayer::Session => self.session_config_path(),
+            ConfigLayer::System => {
+                return Err(ConfigError::ValidationError(
+                    "Cannot write to System layer".to_string(),
+                ))
+            }
+        };
+        let mut file = if path.exists() {
+            let content = std::fs::read_to_string(&path)
+                .map_err(|e| ConfigError::IoError(format!("{}: {}", path.display(), e)))?;
+            serde_json::from_str(&content)
+                .map_err(|e| ConfigError::ParseError(format!("{}: {}", path.display(), e)))?
+        } else {
+            Value::Object(Default::default())
+        };
+        for (pointer, value) in values {
+            let mut target = &mut file;
+            for key in pointer.split('/').skip(1) {
+                let Some(object) = target.as_object_mut() else {
+                    return Err(ConfigError::ValidationError(format!(
+                        "{}: expected a JSON object above {}",
+                        path.display(),
+                        pointer
+                    )));
+                };
+                target = object
+                    .entry(key.replace("~1", "/").replace("~0", "~"))
+                    .or_insert_with(|| Value::Object(Default::default()));
+            }
+            *target = value.clone();
+        }
+
+        if let Some(parent_dir) = path.parent() {
+            std::fs::create_dir_all(parent_dir)
+                .map_err(|e| ConfigError::IoError(format!("{}: {}", parent_dir.display(), e)))?;
+        }
+        let json = serde_json::to_string_pretty(&file)
+            .map_err(|e| ConfigError::SerializeError(e.to_string()))?;
+        std::fs::write(&path, json)
+            .map_err(|e| ConfigError::IoError(format!("{}: {}", path.display(), e)))?;
+
+        Ok(())
+    }
+
     /// Save a SessionConfig to the session layer file.
     pub fn save_session(&self, session: &SessionConfig) -> Result<(), ConfigError> {
         let path = self.session_config_path();
@@ -376,6 +441,13 @@ impl ConfigResolver {
         Ok(merged)
     }
 
+    /// The config a layer inherits: every layer below it merged over the
+    /// system defaults. This is what a setting falls back to when the
+    /// layer has no value of its own.
+    pub fn inherited_config(&self, layer: ConfigLayer) -> Result<Config, ConfigError> {
+        Ok(self.resolve_up_to_layer(layer)?.resolve())
+    }
+
     /// Determine which layer each setting value comes from.
     /// Returns a map of JSON pointer paths to their source layer.
     pub fn get_layer_sources(
@@ -635,6 +707,60 @@ fn is_empty_diff(value: &serde_json::Value) -> bool {
     }
 }
 
+// ============================================================================
+// Settings Audit Log
+// ============================================================================
+
+/// A settings change saved from the Settings UI
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// When the change was saved (RFC 3339, local time)
+    pub timestamp: String,
+    /// JSON pointer of the setting (e.g. "/editor/tab_size")
+    pub path: String,
+    /// Value before the change
+    pub old: Value,
+    /// Value after the change
+    pub new: Value,
+}
+
+/// Most entries the audit log keeps; older ones are dropped as new ones come in
+pub const AUDIT_LOG_MAX_ENTRIES: usize = 1000;
+
+/// Append entries to the audit log, one JSON object per line, keeping only
+/// the newest [`AUDIT_LOG_MAX_ENTRIES`]
+pub fn append_audit_entries(path: &Path, entries: &[AuditEntry]) -> std::io::Result<()> {
+    let existing = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e),
+    };
+    let mut lines: Vec<String> = existing.lines().map(str::to_string).collect();
+    for entry in entries {
+        lines.push(serde_json::to_string(entry)?);
+    }
+    let dropped = lines.len().saturating_sub(AUDIT_LOG_MAX_ENTRIES);
+
+    if let Some(parent_dir) = path.parent() {
+        std::fs::create_dir_all(parent_dir)?;
+    }
+    let mut content = lines[dropped..].join("\n");
+    content.push('\n');
+    std::fs::write(path, content)
+}
+
+/// Read the audit log, oldest first.
+/// A missing file yields no entries; malformed lines are skipped.
+pub fn read_audit_log(path: &Path) -> Vec<AuditEntry> {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
 /// Directory paths for editor state and configuration
 ///
 /// This struct holds all directory paths that the editor needs.
@@ -738,6 +864,17 @@ impl DirectoryContext {
         self.data_dir.join("replace_history.json")
     }
 
+    /// Get the settings audit log file path
+    pub fn settings_audit_log_path(&self) -> std::path::PathBuf {
+        self.data_dir.join("settings_audit.jsonl")
+    }
+
+    /// Get the path of the marker written once the settings dialog's
+    /// "What Changed" panel has been seen
+    pub fn settings_customizations_seen_path(&self) -> std::path::PathBuf {
+        self.data_dir.join("settings_customizations_seen")
+    }
+
     /// Get the terminals root directory
     pub fn terminals_dir(&self) -> std::path::PathBuf {
         self.data_dir.join("terminals")
@@ -784,6 +921,49 @@ mod tests {
         (temp_dir, resolver)
     }
 
+    #[test]
+    fn audit_log_appends_and_reads_back() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = DirectoryContext::for_testing(temp_dir.path()).settings_audit_log_path();
+        assert!(read_audit_log(&path).is_empty());
+
+        let entry = |path: &str, old: Value, new: Value| AuditEntry {
+            timestamp: "2024-01-01T00:00:00+00:00".to_string(),
+            path: path.to_string(),
+            old,
+            new,
+        };
+        let first = entry("/editor/tab_size", Value::from(4), Value::from(2));
+        let second = entry("/check_for_updates", Value::Bool(true), Value::Bool(false));
+        append_audit_entries(&path, std::slice::from_ref(&first)).unwrap();
+        append_audit_entries(&path, std::slice::from_ref(&second)).unwrap();
+
+        assert_eq!(read_audit_log(&path), vec![first, second]);
+    }
+
+    #[test]
+    fn audit_log_keeps_newest_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = DirectoryContext::for_testing(temp_dir.path()).settings_audit_log_path();
+
+        let entries: Vec<AuditEntry> = (0..AUDIT_LOG_MAX_ENTRIES + 5)
+            .map(|i| AuditEntry {
+                timestamp: "2024-01-01T00:00:00+00:00".to_string(),
+                path: "/editor/tab_size".to_string(),
+                old: Value::from(i),
+                new: Value::from(i + 1),
+            })
+            .collect();
+        let (older, newer) = entries.split_at(10);
+        append_audit_entries(&path, older).unwrap();
+        append_audit_entries(&path, newer).unwrap();
+
+        let log = read_audit_log(&path);
+        assert_eq!(log.len(), AUDIT_LOG_MAX_ENTRIES);
+        assert_eq!(log[0], entries[5]);
+        assert_eq!(log.last(), entries.last());
+    }
+
     #[test]
     fn resolver_returns_defaults_when_no_config_files() {
         let (_temp, resolver) = create_test_resolver();
@@ -809,6 +989,53 @@ mod tests {
         drop(temp);
     }
 
+    #[test]
+    fn set_user_value_keeps_other_user_settings() {
+        let (_temp, resolver) = create_test_resolver();
+        resolver
+            .set_user_value("starred_settings", serde_json::json!(["/editor/tab_size"]))
+            .unwrap();
+
+        let user_config_path = resolver.user_config_path();
+        std::fs::write(&user_config_path, r#"{"editor": {"tab_size": 2}}"#).unwrap();
+        resolver
+            .set_user_value("starred_settings", serde_json::json!(["/theme"]))
+            .unwrap();
+
+        let config = resolver.resolve().unwrap();
+        assert_eq!(config.editor.tab_size, 2);
+        assert_eq!(config.starred_settings, vec!["/theme".to_string()]);
+    }
+
+    #[test]
+    fn set_layer_values_creates_nested_objects() {
+        let (_temp, resolver) = create_test_resolver();
+        std::fs::create_dir_all(resolver.user_config_path().parent().unwrap()).unwrap();
+        std::fs::write(
+            resolver.user_config_path(),
+            r#"{"editor": {"tab_size": 2}}"#,
+        )
+        .unwrap();
+        resolver
+            .set_layer_values(
+                ConfigLayer::User,
+                &[
+                    ("/editor/line_numbers".to_string(), serde_json::json!(true)),
+                    (
+                        "/terminal/jump_to_end_on_output".to_string(),
+                        serde_json::json!(false),
+                    ),
+                ],
+            )
+            .unwrap();
+
+        let user = resolver.load_user_layer().unwrap().unwrap();
+        let editor = user.editor.unwrap();
+        assert_eq!(editor.tab_size, Some(2));
+        assert_eq!(editor.line_numbers, Some(true));
+        assert_eq!(user.terminal.unwrap().jump_to_end_on_output, Some(false));
+    }
+
     #[test]
     fn resolver_project_overrides_user() {
         let (temp, resolver) = create_test_resolver();
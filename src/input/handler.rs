@@ -99,6 +99,19 @@ pub enum DeferredAction {
     OpenConfigFile {
         layer: crate::config_io::ConfigLayer,
     },
+    /// Start a background update check, reported in the settings modal
+    CheckForUpdates,
+    /// Remember that the settings "What Changed" panel was dismissed
+    MarkCustomizationsSeen,
+    /// Save the starred settings to the user config
+    SaveStarredSettings,
+    /// Apply the pending settings to the live UI without saving them
+    PreviewSettings,
+    /// Leave the settings preview, saving the previewed changes if `keep`
+    /// or restoring the previous settings otherwise
+    EndSettingsPreview {
+        keep: bool,
+    },
 
     // Menu actions
     CloseMenu,
@@ -862,6 +862,9 @@ impl Action {
             "settings_increment" => Some(Action::SettingsIncrement),
             "settings_decrement" => Some(Action::SettingsDecrement),
 
+            // Unbinds a key, e.g. a keymap key replaced in the settings
+            "noop" => Some(Action::None),
+
             _ => None,
         }
     }
@@ -1461,7 +1464,7 @@ impl KeybindingResolver {
     }
 
     /// Parse a key string to KeyCode
-    fn parse_key(key: &str) -> Option<KeyCode> {
+    pub(crate) fn parse_key(key: &str) -> Option<KeyCode> {
         let lower = key.to_lowercase();
         match lower.as_str() {
             "enter" => Some(KeyCode::Enter),
@@ -1489,7 +1492,7 @@ impl KeybindingResolver {
     }
 
     /// Parse modifiers from strings
-    fn parse_modifiers(modifiers: &[String]) -> KeyModifiers {
+    pub(crate) fn parse_modifiers(modifiers: &[String]) -> KeyModifiers {
         let mut result = KeyModifiers::empty();
         for m in modifiers {
             match m.to_lowercase().as_str() {
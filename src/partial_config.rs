@@ -87,6 +87,8 @@ pub struct PartialConfig {
     pub languages: Option<HashMap<String, PartialLanguageConfig>>,
     pub lsp: Option<HashMap<String, LspServerConfig>>,
     pub warnings: Option<PartialWarningsConfig>,
+    pub settings_last_seen_version: Option<String>,
+    pub starred_settings: Option<Vec<String>>,
 }
 
 impl Merge for PartialConfig {
@@ -113,6 +115,9 @@ impl Merge for PartialConfig {
 
         self.active_keybinding_map
             .merge_from(&other.active_keybinding_map);
+        self.settings_last_seen_version
+            .merge_from(&other.settings_last_seen_version);
+        self.starred_settings.merge_from(&other.starred_settings);
     }
 }
 
@@ -151,6 +156,8 @@ pub struct PartialEditorConfig {
     pub file_tree_poll_interval_ms: Option<u64>,
     pub default_line_ending: Option<LineEndingOption>,
     pub cursor_style: Option<CursorStyle>,
+    pub settings_width_percent: Option<u16>,
+    pub settings_height_percent: Option<u16>,
 }
 
 impl Merge for PartialEditorConfig {
@@ -191,6 +198,10 @@ impl Merge for PartialEditorConfig {
         self.default_line_ending
             .merge_from(&other.default_line_ending);
         self.cursor_style.merge_from(&other.cursor_style);
+        self.settings_width_percent
+            .merge_from(&other.settings_width_percent);
+        self.settings_height_percent
+            .merge_from(&other.settings_height_percent);
     }
 }
 
@@ -322,6 +333,8 @@ impl From<&crate::config::EditorConfig> for PartialEditorConfig {
             file_tree_poll_interval_ms: Some(cfg.file_tree_poll_interval_ms),
             default_line_ending: Some(cfg.default_line_ending.clone()),
             cursor_style: Some(cfg.cursor_style),
+            settings_width_percent: Some(cfg.settings_width_percent),
+            settings_height_percent: Some(cfg.settings_height_percent),
         }
     }
 }
@@ -380,6 +393,12 @@ impl PartialEditorConfig {
                 .default_line_ending
                 .unwrap_or(defaults.default_line_ending.clone()),
             cursor_style: self.cursor_style.unwrap_or(defaults.cursor_style),
+            settings_width_percent: self
+                .settings_width_percent
+                .unwrap_or(defaults.settings_width_percent),
+            settings_height_percent: self
+                .settings_height_percent
+                .unwrap_or(defaults.settings_height_percent),
         }
     }
 }
@@ -532,6 +551,8 @@ impl From<&crate::config::Config> for PartialConfig {
             ),
             lsp: Some(cfg.lsp.clone()),
             warnings: Some(PartialWarningsConfig::from(&cfg.warnings)),
+            settings_last_seen_version: cfg.settings_last_seen_version.clone(),
+            starred_settings: Some(cfg.starred_settings.clone()),
         }
     }
 }
@@ -615,6 +636,12 @@ impl PartialConfig {
                 .warnings
                 .map(|e| e.resolve(&defaults.warnings))
                 .unwrap_or_else(|| defaults.warnings.clone()),
+            settings_last_seen_version: self
+                .settings_last_seen_version
+                .or_else(|| defaults.settings_last_seen_version.clone()),
+            starred_settings: self
+                .starred_settings
+                .unwrap_or_else(|| defaults.starred_settings.clone()),
         }
     }
 }
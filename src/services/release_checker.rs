@@ -72,6 +72,18 @@ pub struct UpdateCheckHandle {
 }
 
 impl UpdateCheckHandle {
+    /// Check for the result without blocking, leaving the check running if
+    /// it has not finished yet.
+    pub fn poll(&self) -> Option<Result<ReleaseCheckResult, String>> {
+        match self.receiver.try_recv() {
+            Ok(result) => Some(result),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => {
+                Some(Err("update check stopped unexpectedly".to_string()))
+            }
+        }
+    }
+
     /// Try to get the result without blocking.
     /// Returns Some(result) if the check completed, None if still running.
     /// If still running, the background thread is abandoned (will be killed on process exit).
@@ -8,7 +8,7 @@
 
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime};
 
 /// Abstraction over time-related operations.
 ///
@@ -18,6 +18,9 @@ pub trait TimeSource: Send + Sync + std::fmt::Debug {
     /// Get the current instant for measuring elapsed time.
     fn now(&self) -> Instant;
 
+    /// Get the current wall-clock time, for timestamps the editor records.
+    fn system_now(&self) -> SystemTime;
+
     /// Sleep for the specified duration.
     ///
     /// In tests, this may be a no-op or advance logical time.
@@ -53,6 +56,10 @@ impl TimeSource for RealTimeSource {
         Instant::now()
     }
 
+    fn system_now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
     fn sleep(&self, duration: Duration) {
         std::thread::sleep(duration);
     }
@@ -84,6 +91,8 @@ pub struct TestTimeSource {
     logical_nanos: AtomicU64,
     /// Base instant (real time at creation, used for Instant arithmetic).
     base_instant: Instant,
+    /// Wall-clock time at creation, advanced along with logical time.
+    base_system_time: SystemTime,
 }
 
 impl Default for TestTimeSource {
@@ -98,6 +107,7 @@ impl TestTimeSource {
         Self {
             logical_nanos: AtomicU64::new(0),
             base_instant: Instant::now(),
+            base_system_time: SystemTime::now(),
         }
     }
 
@@ -137,6 +147,10 @@ impl TimeSource for TestTimeSource {
         self.base_instant + self.elapsed()
     }
 
+    fn system_now(&self) -> SystemTime {
+        self.base_system_time + self.elapsed()
+    }
+
     fn sleep(&self, duration: Duration) {
         // No actual sleeping - just advance logical time.
         // This makes tests run instantly while still simulating time passage.
@@ -186,6 +200,19 @@ mod tests {
         assert!(ts.elapsed_since(start) >= Duration::from_millis(100));
     }
 
+    #[test]
+    fn test_time_source_system_now_advances_with_logical_time() {
+        let ts = TestTimeSource::new();
+        let start = ts.system_now();
+
+        ts.advance(Duration::from_secs(60));
+
+        assert_eq!(
+            ts.system_now().duration_since(start).unwrap(),
+            Duration::from_secs(60)
+        );
+    }
+
     #[test]
     fn test_time_source_reset() {
         let ts = TestTimeSource::new();
@@ -41,7 +41,7 @@ pub use keybinding_list::{
 pub use map_input::{render_map, MapColors, MapEvent, MapLayout, MapState};
 pub use number_input::{
     render_number_input, render_number_input_aligned, NumberInputColors, NumberInputEvent,
-    NumberInputLayout, NumberInputState,
+    NumberInputLayout, NumberInputState, NumberUnit,
 };
 pub use text_input::{
     render_text_input, render_text_input_aligned, TextInputColors, TextInputEvent, TextInputLayout,
@@ -20,6 +20,84 @@ pub use render::{render_number_input, render_number_input_aligned};
 use super::FocusState;
 use crate::view::ui::text_edit::TextEdit;
 
+/// Unit of a number, so values can be typed with a suffix like "2s" or "512kb"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberUnit {
+    Bytes,
+    Milliseconds,
+    Seconds,
+}
+
+impl NumberUnit {
+    /// Unit for a schema `x-unit` name: "bytes", "ms" or "s"
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "bytes" => Some(Self::Bytes),
+            "ms" => Some(Self::Milliseconds),
+            "s" => Some(Self::Seconds),
+            _ => None,
+        }
+    }
+
+    /// Suffixes with the number of base units each stands for, largest
+    /// magnitude first, decimal before binary within a magnitude
+    fn suffixes(self) -> &'static [(&'static str, i64)] {
+        match self {
+            Self::Bytes => &[
+                ("gb", 1_000_000_000),
+                ("gib", 1 << 30),
+                ("mb", 1_000_000),
+                ("mib", 1 << 20),
+                ("kb", 1_000),
+                ("kib", 1 << 10),
+                ("b", 1),
+            ],
+            Self::Milliseconds => &[("h", 3_600_000), ("m", 60_000), ("s", 1_000), ("ms", 1)],
+            Self::Seconds => &[("h", 3_600), ("m", 60), ("s", 1)],
+        }
+    }
+
+    /// Parse text like "512kb" or "1.5s" into base units.
+    /// A plain number is already in base units.
+    pub fn parse(self, text: &str) -> Result<i64, String> {
+        let text = text.trim().to_lowercase();
+        let split = text
+            .find(|c: char| c.is_ascii_alphabetic())
+            .unwrap_or(text.len());
+        let (number, suffix) = text.split_at(split);
+        let multiplier = if suffix.is_empty() {
+            1
+        } else {
+            self.suffixes()
+                .iter()
+                .find(|(s, _)| *s == suffix)
+                .map(|(_, m)| *m)
+                .ok_or_else(|| {
+                    let known: Vec<&str> = self.suffixes().iter().map(|(s, _)| *s).collect();
+                    format!("unknown unit \"{}\", use {}", suffix, known.join(", "))
+                })?
+        };
+        let number: f64 = number
+            .trim()
+            .parse()
+            .map_err(|_| format!("not a number: \"{}\"", number.trim()))?;
+        Ok((number * multiplier as f64).round() as i64)
+    }
+
+    /// Format a value in base units with the largest suffix that divides it
+    /// exactly, e.g. 512000 bytes as "512kb" and 1048576 bytes as "1mib".
+    /// A value both a decimal and a binary unit of the same magnitude
+    /// divide takes the decimal one.
+    pub fn format(self, value: i64) -> String {
+        let suffixes = self.suffixes();
+        let (suffix, multiplier) = suffixes
+            .iter()
+            .find(|(_, m)| value != 0 && value % m == 0)
+            .unwrap_or(&suffixes[suffixes.len() - 1]);
+        format!("{}{}", value / multiplier, suffix)
+    }
+}
+
 /// State for a number input control
 #[derive(Debug, Clone)]
 pub struct NumberInputState {
@@ -40,6 +118,10 @@ pub struct NumberInputState {
     /// Whether this value is a percentage (float value * 100 for display)
     /// When true, the value should be divided by 100 when converting back to JSON
     pub is_percentage: bool,
+    /// Unit of the value, which allows typing it with a suffix
+    pub unit: Option<NumberUnit>,
+    /// Why the edited text was rejected, shown until editing ends
+    pub error: Option<String>,
 }
 
 impl NumberInputState {
@@ -54,6 +136,8 @@ impl NumberInputState {
             focus: FocusState::Normal,
             editor: None,
             is_percentage: false,
+            unit: None,
+            error: None,
         }
     }
 
@@ -92,11 +176,25 @@ impl NumberInputState {
         self
     }
 
+    /// Set the unit of the value
+    pub fn with_unit(mut self, unit: NumberUnit) -> Self {
+        self.unit = Some(unit);
+        self
+    }
+
     /// Check if the control is enabled
     pub fn is_enabled(&self) -> bool {
         self.focus != FocusState::Disabled
     }
 
+    /// Whether `c` can be typed into the value
+    fn accepts_char(&self, c: char) -> bool {
+        c.is_ascii_digit()
+            || c == '-'
+            || c == '.'
+            || (self.unit.is_some() && c.is_ascii_alphabetic())
+    }
+
     /// Increment the value by step
     pub fn increment(&mut self) {
         if !self.is_enabled() {
@@ -151,23 +249,41 @@ impl NumberInputState {
     /// Cancel editing and restore original value
     pub fn cancel_editing(&mut self) {
         self.editor = None;
+        self.error = None;
     }
 
-    /// Confirm editing and apply the new value
+    /// Confirm editing and apply the new value.
+    /// With a unit, text that doesn't parse keeps editing open with an error.
     pub fn confirm_editing(&mut self) {
-        if let Some(editor) = self.editor.take() {
-            if let Ok(new_value) = editor.value().parse::<i64>() {
-                self.set_value(new_value);
+        let Some(editor) = self.editor.take() else {
+            return;
+        };
+        match self.unit {
+            Some(unit) => match unit.parse(&editor.value()) {
+                Ok(new_value) => {
+                    self.error = None;
+                    self.set_value(new_value);
+                }
+                Err(error) => {
+                    self.error = Some(error);
+                    self.editor = Some(editor);
+                }
+            },
+            None => {
+                if let Ok(new_value) = editor.value().parse::<i64>() {
+                    self.set_value(new_value);
+                }
             }
         }
     }
 
     /// Insert a character while editing
-    /// Allows digits, minus sign, and decimal point for number input
+    /// Allows digits, minus sign, and decimal point for number input,
+    /// plus letters for a unit suffix when the value has a unit
     pub fn insert_char(&mut self, c: char) {
+        let accepted = self.accepts_char(c);
         if let Some(editor) = &mut self.editor {
-            // Allow digits, minus sign, and decimal point
-            if c.is_ascii_digit() || c == '-' || c == '.' {
+            if accepted {
                 editor.insert_char(c);
             }
         }
@@ -304,12 +420,9 @@ impl NumberInputState {
 
     /// Insert string at cursor (for paste)
     pub fn insert_str(&mut self, text: &str) {
+        // Filter to only allow valid number characters
+        let filtered: String = text.chars().filter(|c| self.accepts_char(*c)).collect();
         if let Some(editor) = &mut self.editor {
-            // Filter to only allow valid number characters
-            let filtered: String = text
-                .chars()
-                .filter(|c| c.is_ascii_digit() || *c == '-' || *c == '.')
-                .collect();
             editor.insert_str(&filtered);
         }
     }
@@ -318,6 +431,8 @@ impl NumberInputState {
     pub fn display_text(&self) -> String {
         if let Some(editor) = &self.editor {
             editor.value()
+        } else if let Some(unit) = self.unit {
+            unit.format(self.value)
         } else {
             self.value.to_string()
         }
@@ -360,6 +475,8 @@ pub struct NumberInputColors {
     pub focused: Color,
     /// Disabled color
     pub disabled: Color,
+    /// Color of the message for rejected input
+    pub error: Color,
 }
 
 impl Default for NumberInputColors {
@@ -371,6 +488,7 @@ impl Default for NumberInputColors {
             button: Color::Cyan,
             focused: Color::Cyan,
             disabled: Color::DarkGray,
+            error: Color::Red,
         }
     }
 }
@@ -385,6 +503,7 @@ impl NumberInputColors {
             button: theme.menu_active_fg,
             focused: theme.selection_bg,
             disabled: theme.line_number_fg,
+            error: theme.diagnostic_error_fg,
         }
     }
 }
@@ -695,4 +814,42 @@ mod tests {
         state.move_end();
         assert_eq!(state.cursor_col(), 3);
     }
+
+    #[test]
+    fn test_number_unit_parse_and_format() {
+        assert_eq!(NumberUnit::Bytes.parse("512kb"), Ok(512_000));
+        assert_eq!(NumberUnit::Bytes.parse("1 MiB"), Ok(1_048_576));
+        assert_eq!(NumberUnit::Milliseconds.parse("1.5s"), Ok(1_500));
+        assert_eq!(NumberUnit::Seconds.parse("42"), Ok(42));
+        assert!(NumberUnit::Bytes.parse("2xb").is_err());
+        assert!(NumberUnit::Seconds.parse("ms").is_err());
+
+        assert_eq!(NumberUnit::Bytes.format(512_000), "512kb");
+        assert_eq!(NumberUnit::Bytes.format(1_048_576), "1mib");
+        assert_eq!(NumberUnit::Bytes.format(1_000_000), "1mb");
+        assert_eq!(NumberUnit::Bytes.format(2_048), "2kib");
+        assert_eq!(NumberUnit::Milliseconds.format(500), "500ms");
+        assert_eq!(NumberUnit::Milliseconds.format(0), "0ms");
+    }
+
+    #[test]
+    fn test_number_input_rejects_unknown_unit() {
+        let mut state = NumberInputState::new(2, "Interval").with_unit(NumberUnit::Seconds);
+        assert_eq!(state.display_text(), "2s");
+
+        state.start_editing();
+        state.insert_str("3x");
+        state.confirm_editing();
+        assert!(state.editing());
+        assert!(state.error.is_some());
+        assert_eq!(state.value, 2);
+
+        state.select_all();
+        state.insert_str("2m");
+        state.confirm_editing();
+        assert!(!state.editing());
+        assert_eq!(state.error, None);
+        assert_eq!(state.value, 120);
+        assert_eq!(state.display_text(), "2m");
+    }
 }
@@ -94,6 +94,12 @@ pub fn render_number_input_aligned(
         Span::raw(" "),
         Span::styled("[+]", Style::default().fg(button_color)),
     ]);
+    if let Some(error) = &state.error {
+        spans.push(Span::styled(
+            format!(" {}", error),
+            Style::default().fg(colors.error),
+        ));
+    }
 
     let line = Line::from(spans);
 
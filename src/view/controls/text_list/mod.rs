@@ -98,6 +98,26 @@ impl TextListState {
         }
     }
 
+    /// Swap the focused item with its neighbour, `up` towards the start of
+    /// the list. Focus follows the moved item.
+    pub fn move_focused_item(&mut self, up: bool) {
+        if !self.is_enabled() {
+            return;
+        }
+        let Some(idx) = self.focused_item else {
+            return;
+        };
+        let target = if up {
+            idx.checked_sub(1)
+        } else {
+            Some(idx + 1).filter(|&t| t < self.items.len())
+        };
+        if let Some(target) = target {
+            self.items.swap(idx, target);
+            self.focused_item = Some(target);
+        }
+    }
+
     /// Focus on an item for editing
     pub fn focus_item(&mut self, index: usize) {
         if index < self.items.len() {
@@ -408,6 +428,27 @@ mod tests {
         assert_eq!(state.items[0], "b");
     }
 
+    #[test]
+    fn test_text_list_move_focused_item() {
+        let mut state = TextListState::new("Items").with_items(vec![
+            "a".to_string(),
+            "b".to_string(),
+            "c".to_string(),
+        ]);
+        state.focus_item(0);
+        state.move_focused_item(true);
+        assert_eq!(state.items, vec!["a", "b", "c"]);
+
+        state.move_focused_item(false);
+        assert_eq!(state.items, vec!["b", "a", "c"]);
+        assert_eq!(state.focused_item, Some(1));
+
+        state.move_focused_item(false);
+        state.move_focused_item(false);
+        assert_eq!(state.items, vec!["b", "c", "a"]);
+        assert_eq!(state.focused_item, Some(2));
+    }
+
     #[test]
     fn test_text_list_edit_item() {
         let mut state = TextListState::new("Items").with_items(vec!["hello".to_string()]);
@@ -39,7 +39,7 @@ impl ToggleState {
         match event.kind {
             MouseEventKind::Down(MouseButton::Left) if inside => {
                 self.toggle();
-                Some(ToggleEvent::Toggled(self.checked))
+                Some(ToggleEvent::Toggled(self.checked()))
             }
             MouseEventKind::Moved => {
                 if inside {
@@ -73,7 +73,7 @@ impl ToggleState {
         match key.code {
             KeyCode::Enter | KeyCode::Char(' ') => {
                 self.toggle();
-                Some(ToggleEvent::Toggled(self.checked))
+                Some(ToggleEvent::Toggled(self.checked()))
             }
             _ => None,
         }
@@ -118,11 +118,11 @@ mod tests {
 
         let result = state.handle_mouse(mouse_down(5, 0), &layout);
         assert_eq!(result, Some(ToggleEvent::Toggled(true)));
-        assert!(state.checked);
+        assert!(state.checked());
 
         let result = state.handle_mouse(mouse_down(5, 0), &layout);
         assert_eq!(result, Some(ToggleEvent::Toggled(false)));
-        assert!(!state.checked);
+        assert!(!state.checked());
     }
 
     #[test]
@@ -132,7 +132,7 @@ mod tests {
 
         let result = state.handle_mouse(mouse_down(15, 0), &layout);
         assert!(result.is_none());
-        assert!(!state.checked);
+        assert!(!state.checked());
     }
 
     #[test]
@@ -156,7 +156,7 @@ mod tests {
 
         let result = state.handle_mouse(mouse_down(5, 0), &layout);
         assert!(result.is_none());
-        assert!(!state.checked);
+        assert!(!state.checked());
     }
 
     #[test]
@@ -166,12 +166,12 @@ mod tests {
         let enter = crossterm::event::KeyEvent::new(KeyCode::Enter, KeyModifiers::empty());
         let result = state.handle_key(enter);
         assert_eq!(result, Some(ToggleEvent::Toggled(true)));
-        assert!(state.checked);
+        assert!(state.checked());
 
         let space = crossterm::event::KeyEvent::new(KeyCode::Char(' '), KeyModifiers::empty());
         let result = state.handle_key(space);
         assert_eq!(result, Some(ToggleEvent::Toggled(false)));
-        assert!(!state.checked);
+        assert!(!state.checked());
     }
 
     #[test]
@@ -181,6 +181,6 @@ mod tests {
         let enter = crossterm::event::KeyEvent::new(KeyCode::Enter, KeyModifiers::empty());
         let result = state.handle_key(enter);
         assert!(result.is_none());
-        assert!(!state.checked);
+        assert!(!state.checked());
     }
 }
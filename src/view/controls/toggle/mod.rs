@@ -1,6 +1,8 @@
 //! Toggle (checkbox) control for boolean values
 //!
-//! Renders as: `Label: [x]` or `Label: [ ]`
+//! Renders as: `Label: [x]` or `Label: [ ]`, followed by `(inherited)` when
+//! the toggle has no value of its own and shows the one from a lower config
+//! layer
 //!
 //! This module provides a complete toggle component with:
 //! - State management (`ToggleState`)
@@ -22,8 +24,10 @@ use super::FocusState;
 /// State for a toggle control
 #[derive(Debug, Clone)]
 pub struct ToggleState {
-    /// Current value
-    pub checked: bool,
+    /// Explicit value, or `None` to inherit one from a lower config layer
+    pub value: Option<bool>,
+    /// Value shown while inheriting
+    pub inherited_value: bool,
     /// Label displayed next to the toggle
     pub label: String,
     /// Focus state
@@ -34,7 +38,8 @@ impl ToggleState {
     /// Create a new toggle state
     pub fn new(checked: bool, label: impl Into<String>) -> Self {
         Self {
-            checked,
+            value: Some(checked),
+            inherited_value: checked,
             label: label.into(),
             focus: FocusState::Normal,
         }
@@ -51,10 +56,33 @@ impl ToggleState {
         self.focus != FocusState::Disabled
     }
 
+    /// The value in effect, explicit or inherited
+    pub fn checked(&self) -> bool {
+        self.value.unwrap_or(self.inherited_value)
+    }
+
+    /// Check if the toggle inherits its value
+    pub fn is_inherited(&self) -> bool {
+        self.value.is_none()
+    }
+
     /// Toggle the value
     pub fn toggle(&mut self) {
         if self.is_enabled() {
-            self.checked = !self.checked;
+            self.value = Some(!self.checked());
+        }
+    }
+
+    /// Step through the value opposite to the inherited one, the inherited
+    /// value set explicitly, and inheriting. Leaving the inherit state
+    /// always changes the value shown.
+    pub fn cycle(&mut self) {
+        if self.is_enabled() {
+            self.value = match self.value {
+                None => Some(!self.inherited_value),
+                Some(value) if value != self.inherited_value => Some(self.inherited_value),
+                Some(_) => None,
+            };
         }
     }
 }
@@ -183,20 +211,37 @@ mod tests {
     #[test]
     fn test_toggle_state_toggle() {
         let mut state = ToggleState::new(false, "Test");
-        assert!(!state.checked);
+        assert!(!state.checked());
 
         state.toggle();
-        assert!(state.checked);
+        assert!(state.checked());
 
         state.toggle();
-        assert!(!state.checked);
+        assert!(!state.checked());
+    }
+
+    #[test]
+    fn test_toggle_state_cycle() {
+        let mut state = ToggleState::new(true, "Test");
+        state.inherited_value = false;
+
+        state.cycle();
+        assert_eq!(state.value, Some(false));
+        assert!(!state.checked());
+
+        state.cycle();
+        assert!(state.is_inherited());
+        assert!(!state.checked());
+
+        state.cycle();
+        assert_eq!(state.value, Some(true));
     }
 
     #[test]
     fn test_toggle_disabled_no_toggle() {
         let mut state = ToggleState::new(false, "Test").with_focus(FocusState::Disabled);
         state.toggle();
-        assert!(!state.checked); // Should not change
+        assert!(!state.checked()); // Should not change
     }
 
     #[test]
@@ -1,7 +1,7 @@
 //! Toggle rendering functions
 
 use ratatui::layout::Rect;
-use ratatui::style::Style;
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 use ratatui::Frame;
@@ -52,14 +52,15 @@ pub fn render_toggle_aligned(
         };
     }
 
-    let (bracket_color, _check_color, label_color) = match state.focus {
+    let (bracket_color, check_color, label_color) = match state.focus {
         FocusState::Normal => (colors.bracket, colors.checkmark, colors.label),
         FocusState::Focused => (colors.focused, colors.checkmark, colors.focused),
         FocusState::Hovered => (colors.focused, colors.checkmark, colors.focused),
         FocusState::Disabled => (colors.disabled, colors.disabled, colors.disabled),
     };
 
-    let checkbox = if state.checked { "[x]" } else { "[ ]" };
+    // The mark gets its own color so on/off can be told apart at a glance
+    let mark = if state.checked() { "x" } else { " " };
 
     // Format: "Label: [x]" with optional padding
     let actual_label_width = label_width.unwrap_or(state.label.len() as u16);
@@ -72,8 +73,25 @@ pub fn render_toggle_aligned(
     let line = Line::from(vec![
         Span::styled(padded_label, Style::default().fg(label_color)),
         Span::styled(": ", Style::default().fg(label_color)),
-        Span::styled(checkbox, Style::default().fg(bracket_color)),
+        Span::styled("[", Style::default().fg(bracket_color)),
+        Span::styled(
+            mark,
+            Style::default()
+                .fg(check_color)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("]", Style::default().fg(bracket_color)),
     ]);
+    let line = if state.is_inherited() {
+        let mut line = line;
+        line.spans.push(Span::styled(
+            " (inherited)",
+            Style::default().fg(colors.disabled),
+        ));
+        line
+    } else {
+        line
+    };
 
     let paragraph = Paragraph::new(line);
     frame.render_widget(paragraph, area);
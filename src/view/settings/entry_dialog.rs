@@ -68,6 +68,9 @@ impl EntryDialogState {
             control: SettingControl::Text(TextInputState::new("Key").with_value(&key)),
             default: None,
             modified: false,
+            file_path: false,
+            since: None,
+            doc_url: None,
         };
         items.push(key_item);
 
@@ -460,6 +463,7 @@ impl EntryDialogState {
                 SettingControl::Map(s) => s.focus = state,
                 SettingControl::ObjectArray(s) => s.focus = state,
                 SettingControl::Json(s) => s.focus = state,
+                SettingControl::KeyChord(s) => s.focus = state,
                 SettingControl::Complex { .. } => {}
             }
         }
@@ -817,7 +821,7 @@ impl EntryDialogState {
     pub fn toggle_bool(&mut self) {
         if let Some(item) = self.current_item_mut() {
             if let SettingControl::Toggle(state) = &mut item.control {
-                state.checked = !state.checked;
+                state.value = Some(!state.checked());
             }
         }
     }
@@ -1000,6 +1004,10 @@ mod tests {
                         description: Some("Enable this".to_string()),
                         setting_type: SettingType::Boolean,
                         default: Some(serde_json::json!(true)),
+                        advanced: false,
+                        file_path: false,
+                        since: None,
+                        doc_url: None,
                     },
                     SettingSchema {
                         path: "/command".to_string(),
@@ -1007,10 +1015,18 @@ mod tests {
                         description: Some("Command to run".to_string()),
                         setting_type: SettingType::String,
                         default: Some(serde_json::json!("")),
+                        advanced: false,
+                        file_path: false,
+                        since: None,
+                        doc_url: None,
                     },
                 ],
             },
             default: None,
+            advanced: false,
+            file_path: false,
+            since: None,
+            doc_url: None,
         }
     }
 
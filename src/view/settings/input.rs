@@ -4,7 +4,8 @@
 //! through the focus hierarchy: Dialog -> Panel -> Control.
 
 use super::items::SettingControl;
-use super::state::{FocusPanel, SettingsState};
+use super::keybindings::key_event_binding;
+use super::state::{FocusPanel, NotificationKind, SettingsState};
 use crate::input::handler::{DeferredAction, InputContext, InputHandler, InputResult};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
@@ -20,11 +21,17 @@ enum ControlAction {
     ToggleBool,
     ToggleDropdown,
     StartEditing,
+    PickPath,
     OpenNestedDialog,
 }
 
 impl InputHandler for SettingsState {
     fn handle_key_event(&mut self, event: &KeyEvent, ctx: &mut InputContext) -> InputResult {
+        // Path picker sits above everything, including entry dialogs
+        if self.path_picker.is_some() {
+            return self.handle_path_picker_input(event);
+        }
+
         // Entry dialog takes priority when open
         if self.has_entry_dialog() {
             return self.handle_entry_dialog_input(event, ctx);
@@ -40,17 +47,57 @@ impl InputHandler for SettingsState {
             return self.handle_help_input(event, ctx);
         }
 
+        // Any key dismisses the "what changed" panel
+        if self.showing_customizations {
+            self.showing_customizations = false;
+            ctx.defer(DeferredAction::MarkCustomizationsSeen);
+            return InputResult::Consumed;
+        }
+
         // Search mode takes priority
         if self.search_active {
             return self.handle_search_input(event, ctx);
         }
 
-        // Global shortcut: Ctrl+S to save
+        // Find/replace over values takes priority
+        if self.value_replace.is_some() {
+            return self.handle_value_replace_input(event);
+        }
+
+        // Go to setting by path takes priority
+        if self.go_to_path.is_some() {
+            return self.handle_go_to_path_input(event);
+        }
+
+        // Previewed changes wait to be kept or reverted
+        if self.previewing {
+            return self.handle_preview_input(event, ctx);
+        }
+
+        // A keybinding waiting for its new key takes every key, even Ctrl+S
+        if self.is_capturing_key() {
+            return self.handle_key_capture_input(event);
+        }
+
+        // Global shortcuts: Ctrl+S to save, Ctrl+P to preview, Ctrl+R to
+        // replace in values
         if event.modifiers.contains(KeyModifiers::CONTROL) {
             if matches!(event.code, KeyCode::Char('s') | KeyCode::Char('S')) {
                 ctx.defer(DeferredAction::CloseSettings { save: true });
                 return InputResult::Consumed;
             }
+            if matches!(event.code, KeyCode::Char('p') | KeyCode::Char('P')) {
+                if self.has_changes() {
+                    ctx.defer(DeferredAction::PreviewSettings);
+                } else {
+                    self.notify("No changes to preview", NotificationKind::Info);
+                }
+                return InputResult::Consumed;
+            }
+            if matches!(event.code, KeyCode::Char('r') | KeyCode::Char('R')) {
+                self.start_value_replace();
+                return InputResult::Consumed;
+            }
         }
 
         // Route to focused panel
@@ -67,6 +114,28 @@ impl InputHandler for SettingsState {
 }
 
 impl SettingsState {
+    /// Handle input while pending changes are previewed: Enter keeps and
+    /// saves them, Esc reverts the live UI and leaves them pending
+    fn handle_preview_input(&mut self, event: &KeyEvent, ctx: &mut InputContext) -> InputResult {
+        match event.code {
+            KeyCode::Enter => ctx.defer(DeferredAction::EndSettingsPreview { keep: true }),
+            KeyCode::Esc => ctx.defer(DeferredAction::EndSettingsPreview { keep: false }),
+            _ => {}
+        }
+        InputResult::Consumed
+    }
+
+    /// Handle the key press that becomes the focused action's binding.
+    /// Esc cancels.
+    fn handle_key_capture_input(&mut self, event: &KeyEvent) -> InputResult {
+        if event.code == KeyCode::Esc && event.modifiers.is_empty() {
+            self.cancel_key_capture();
+        } else if let Some(key) = key_event_binding(event) {
+            self.rebind_current(Some(key));
+        }
+        InputResult::Consumed
+    }
+
     /// Handle input when entry dialog is open
     ///
     /// Uses the same input flow as the main settings UI:
@@ -354,6 +423,9 @@ impl SettingsState {
                             dialog.current_item().map(|item| match &item.control {
                                 SettingControl::Toggle(_) => Some(ControlAction::ToggleBool),
                                 SettingControl::Dropdown(_) => Some(ControlAction::ToggleDropdown),
+                                SettingControl::Text(_) if item.file_path => {
+                                    Some(ControlAction::PickPath)
+                                }
                                 SettingControl::Text(_)
                                 | SettingControl::TextList(_)
                                 | SettingControl::Number(_)
@@ -383,6 +455,9 @@ impl SettingsState {
                                     dialog.start_editing();
                                 }
                             }
+                            ControlAction::PickPath => {
+                                self.open_path_picker();
+                            }
                             ControlAction::OpenNestedDialog => {
                                 // Handle nested Map or ObjectArray - open a nested dialog
                                 self.open_nested_entry_dialog();
@@ -396,6 +471,62 @@ impl SettingsState {
         InputResult::Consumed
     }
 
+    /// Handle input when the path picker is open
+    fn handle_path_picker_input(&mut self, event: &KeyEvent) -> InputResult {
+        let Some(picker) = self.path_picker.as_mut() else {
+            return InputResult::Consumed;
+        };
+        match event.code {
+            KeyCode::Esc => self.path_picker = None,
+            KeyCode::Up => picker.select_prev(),
+            KeyCode::Down => picker.select_next(),
+            KeyCode::Tab => picker.complete(),
+            KeyCode::Backspace => picker.backspace(),
+            KeyCode::Enter => {
+                if let Some(path) = picker.activate() {
+                    self.confirm_path_picker(&path);
+                }
+            }
+            KeyCode::Char(c) => picker.push_char(c),
+            _ => {}
+        }
+        InputResult::Consumed
+    }
+
+    /// Handle input while finding/replacing in setting values
+    fn handle_value_replace_input(&mut self, event: &KeyEvent) -> InputResult {
+        let editing_replace = self
+            .value_replace
+            .as_ref()
+            .is_some_and(|replace| replace.editing_replace);
+        match event.code {
+            KeyCode::Esc => self.cancel_value_replace(),
+            KeyCode::Enter if editing_replace => {
+                self.apply_value_replace();
+            }
+            KeyCode::Enter | KeyCode::Tab | KeyCode::BackTab => self.value_replace_switch_field(),
+            KeyCode::Backspace => self.value_replace_pop_char(),
+            KeyCode::Char(c) => self.value_replace_push_char(c),
+            _ => {}
+        }
+        InputResult::Consumed
+    }
+
+    /// Handle input while typing a setting path after `:`
+    fn handle_go_to_path_input(&mut self, event: &KeyEvent) -> InputResult {
+        match event.code {
+            KeyCode::Esc => self.cancel_go_to_path(),
+            KeyCode::Enter => {
+                self.apply_go_to_path();
+            }
+            KeyCode::Tab => self.complete_go_to_path(),
+            KeyCode::Backspace => self.go_to_path_pop_char(),
+            KeyCode::Char(c) => self.go_to_path_push_char(c),
+            _ => {}
+        }
+        InputResult::Consumed
+    }
+
     /// Handle input when confirmation dialog is showing
     fn handle_confirm_dialog_input(
         &mut self,
@@ -422,6 +553,9 @@ impl SettingsState {
                     2 => self.showing_confirm_dialog = false, // Cancel - back to settings
                     _ => {}
                 }
+                if self.confirm_dialog_selection < 2 {
+                    self.last_confirm_choice = self.confirm_dialog_selection;
+                }
                 InputResult::Consumed
             }
             KeyCode::Esc => {
@@ -429,10 +563,12 @@ impl SettingsState {
                 InputResult::Consumed
             }
             KeyCode::Char('s') | KeyCode::Char('S') => {
+                self.last_confirm_choice = 0;
                 ctx.defer(DeferredAction::CloseSettings { save: true });
                 InputResult::Consumed
             }
             KeyCode::Char('d') | KeyCode::Char('D') => {
+                self.last_confirm_choice = 1;
                 ctx.defer(DeferredAction::CloseSettings { save: false });
                 InputResult::Consumed
             }
@@ -466,6 +602,10 @@ impl SettingsState {
                 self.search_next();
                 InputResult::Consumed
             }
+            KeyCode::Tab => {
+                self.toggle_search_values();
+                InputResult::Consumed
+            }
             KeyCode::Char(c) => {
                 self.search_push_char(c);
                 InputResult::Consumed
@@ -480,6 +620,10 @@ impl SettingsState {
 
     /// Handle input when Categories panel is focused
     fn handle_categories_input(&mut self, event: &KeyEvent, ctx: &mut InputContext) -> InputResult {
+        if let Some(result) = self.handle_chord(event) {
+            return result;
+        }
+
         match event.code {
             KeyCode::Up => {
                 self.select_prev();
@@ -497,10 +641,22 @@ impl SettingsState {
                 self.start_search();
                 InputResult::Consumed
             }
+            KeyCode::Char(':') => {
+                self.start_go_to_path();
+                InputResult::Consumed
+            }
             KeyCode::Char('?') => {
                 self.toggle_help();
                 InputResult::Consumed
             }
+            KeyCode::Char('a') => {
+                self.toggle_show_advanced();
+                InputResult::Consumed
+            }
+            KeyCode::Char('u') => {
+                ctx.defer(DeferredAction::CheckForUpdates);
+                InputResult::Consumed
+            }
             KeyCode::Esc => {
                 self.request_close(ctx);
                 InputResult::Consumed
@@ -514,6 +670,73 @@ impl SettingsState {
         }
     }
 
+    /// Handle vim-style navigation chords: `gg` jumps to the first item and
+    /// `G` to the last. `RR` resets every setting to its default, the first
+    /// `R` asking for confirmation. Returns None if the key is not part of
+    /// a chord.
+    fn handle_chord(&mut self, event: &KeyEvent) -> Option<InputResult> {
+        // The reset prompt only holds while its notification is shown
+        if self.pending_chord == Some('R') && self.current_notification().is_none() {
+            self.pending_chord = None;
+        }
+        if let Some(prefix) = self.pending_chord.take() {
+            // Any key answers the reset prompt; after `g`, a key that does
+            // not complete the chord is handled as usual
+            match (prefix, event.code) {
+                ('g', KeyCode::Char('g')) => self.select_first(),
+                ('R', KeyCode::Char('R')) => {
+                    let (count, overridden) = self.reset_all_to_defaults();
+                    let reset = if count == 1 {
+                        "Reset 1 setting to its default".to_string()
+                    } else {
+                        format!("Reset {} settings to their defaults", count)
+                    };
+                    if overridden == 0 {
+                        self.notify(reset, NotificationKind::Success);
+                    } else {
+                        self.notify(
+                            format!("{}; {} still set by a higher layer", reset, overridden),
+                            NotificationKind::Info,
+                        );
+                    }
+                }
+                // The reset prompt no longer applies
+                ('R', _) => self.notification = None,
+                _ => return self.start_chord(event),
+            }
+            return Some(InputResult::Consumed);
+        }
+        self.start_chord(event)
+    }
+
+    /// Handle a key that may start a chord or is a single-key motion
+    fn start_chord(&mut self, event: &KeyEvent) -> Option<InputResult> {
+        if event.modifiers.contains(KeyModifiers::CONTROL)
+            || event.modifiers.contains(KeyModifiers::ALT)
+        {
+            return None;
+        }
+        match event.code {
+            KeyCode::Char('g') => {
+                self.pending_chord = Some('g');
+                Some(InputResult::Consumed)
+            }
+            KeyCode::Char('G') => {
+                self.select_last();
+                Some(InputResult::Consumed)
+            }
+            KeyCode::Char('R') => {
+                self.pending_chord = Some('R');
+                self.notify(
+                    "Press R again to reset ALL settings to their defaults",
+                    NotificationKind::Info,
+                );
+                Some(InputResult::Consumed)
+            }
+            _ => None,
+        }
+    }
+
     /// Handle input when Settings panel is focused
     fn handle_settings_input(&mut self, event: &KeyEvent, ctx: &mut InputContext) -> InputResult {
         // If editing text, handle text input
@@ -531,6 +754,10 @@ impl SettingsState {
             return self.handle_dropdown_input(event, ctx);
         }
 
+        if let Some(result) = self.handle_chord(event) {
+            return result;
+        }
+
         match event.code {
             KeyCode::Up => {
                 self.select_prev();
@@ -545,11 +772,15 @@ impl SettingsState {
                 InputResult::Consumed
             }
             KeyCode::Left => {
-                self.handle_control_decrement();
+                if !self.select_column(false) {
+                    self.handle_control_decrement();
+                }
                 InputResult::Consumed
             }
             KeyCode::Right => {
-                self.handle_control_increment();
+                if !self.select_column(true) {
+                    self.handle_control_increment();
+                }
                 InputResult::Consumed
             }
             KeyCode::Enter | KeyCode::Char(' ') => {
@@ -560,12 +791,37 @@ impl SettingsState {
                 self.start_search();
                 InputResult::Consumed
             }
+            KeyCode::Char(':') => {
+                self.start_go_to_path();
+                InputResult::Consumed
+            }
             KeyCode::Char('?') => {
                 self.toggle_help();
                 InputResult::Consumed
             }
+            KeyCode::Char('a') => {
+                self.toggle_show_advanced();
+                InputResult::Consumed
+            }
+            KeyCode::Char('u') => {
+                ctx.defer(DeferredAction::CheckForUpdates);
+                InputResult::Consumed
+            }
+            KeyCode::Char('*') => {
+                if self.toggle_star_current() {
+                    ctx.defer(DeferredAction::SaveStarredSettings);
+                }
+                InputResult::Consumed
+            }
+            KeyCode::Char('o') => {
+                self.show_current_doc_url();
+                InputResult::Consumed
+            }
             KeyCode::Esc => {
-                self.request_close(ctx);
+                // After jumping from search, go back to the results first
+                if !self.return_to_search_results() {
+                    self.request_close(ctx);
+                }
                 InputResult::Consumed
             }
             _ => InputResult::Ignored, // Let modal catch it
@@ -625,6 +881,10 @@ impl SettingsState {
                 self.start_search();
                 InputResult::Consumed
             }
+            KeyCode::Char(':') => {
+                self.start_go_to_path();
+                InputResult::Consumed
+            }
             KeyCode::Char('?') => {
                 self.toggle_help();
                 InputResult::Consumed
@@ -672,6 +932,14 @@ impl SettingsState {
                 self.text_move_right();
                 InputResult::Consumed
             }
+            KeyCode::Up if event.modifiers.contains(KeyModifiers::ALT) => {
+                self.text_move_focused_item(true);
+                InputResult::Consumed
+            }
+            KeyCode::Down if event.modifiers.contains(KeyModifiers::ALT) => {
+                self.text_move_focused_item(false);
+                InputResult::Consumed
+            }
             KeyCode::Up => {
                 self.text_focus_prev();
                 InputResult::Consumed
@@ -793,8 +1061,7 @@ impl SettingsState {
     /// Request to close settings (shows confirm dialog if there are changes)
     fn request_close(&mut self, ctx: &mut InputContext) {
         if self.has_changes() {
-            self.showing_confirm_dialog = true;
-            self.confirm_dialog_selection = 0;
+            self.show_confirm_dialog();
         } else {
             ctx.defer(DeferredAction::CloseSettings { save: false });
         }
@@ -803,10 +1070,10 @@ impl SettingsState {
     /// Handle control activation (Enter/Space on a setting)
     fn handle_control_activate(&mut self, _ctx: &mut InputContext) {
         if let Some(item) = self.current_item_mut() {
+            let file_path = item.file_path;
             match &mut item.control {
-                SettingControl::Toggle(ref mut state) => {
-                    state.checked = !state.checked;
-                    self.on_value_changed();
+                SettingControl::Toggle(_) => {
+                    self.toggle_current();
                 }
                 SettingControl::Dropdown(_) => {
                     self.dropdown_toggle();
@@ -814,6 +1081,9 @@ impl SettingsState {
                 SettingControl::Number(_) => {
                     self.start_number_editing();
                 }
+                SettingControl::Text(_) if file_path => {
+                    self.open_path_picker();
+                }
                 SettingControl::Text(_) => {
                     self.start_editing();
                 }
@@ -844,6 +1114,9 @@ impl SettingsState {
                 SettingControl::Json(_) => {
                     self.start_editing();
                 }
+                SettingControl::KeyChord(ref mut state) => {
+                    state.capturing = true;
+                }
                 SettingControl::ObjectArray(ref state) => {
                     if state.focused_index.is_none() {
                         // On add-new row: open dialog with empty item
@@ -2,6 +2,7 @@
 //!
 //! Converts schema information into renderable setting items.
 
+use super::keybindings::{build_keybindings_page, KeyChordState};
 use super::schema::{SettingCategory, SettingSchema, SettingType};
 use crate::view::controls::{
     DropdownState, FocusState, KeybindingListState, MapState, NumberInputState, TextInputState,
@@ -229,6 +230,12 @@ pub struct SettingItem {
     pub default: Option<serde_json::Value>,
     /// Whether this setting has been modified from default
     pub modified: bool,
+    /// Whether the value is a filesystem path (edited with the path picker)
+    pub file_path: bool,
+    /// Editor version that introduced this setting
+    pub since: Option<String>,
+    /// Link to documentation for this setting
+    pub doc_url: Option<String>,
 }
 
 /// The type of control to render for a setting
@@ -245,6 +252,8 @@ pub enum SettingControl {
     ObjectArray(KeybindingListState),
     /// Multiline JSON editor
     Json(JsonEditState),
+    /// Key bound to an action, on the Keybindings page
+    KeyChord(KeyChordState),
     /// Complex settings that can't be edited inline
     Complex {
         type_name: String,
@@ -303,7 +312,53 @@ impl SettingControl {
     }
 }
 
+/// Compare versions by semver precedence: the numeric parts first, then
+/// the pre-release, which sorts before its release ("0.2.0-rc.1" < "0.2.0")
+fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    fn split(version: &str) -> (Vec<u64>, Option<&str>) {
+        let version = version.split('+').next().unwrap_or(version);
+        let (core, pre) = match version.split_once('-') {
+            Some((core, pre)) => (core, Some(pre)),
+            None => (version, None),
+        };
+        let parts = core
+            .split('.')
+            .map(|part| part.parse().unwrap_or(0))
+            .collect();
+        (parts, pre)
+    }
+
+    let (a_parts, a_pre) = split(a);
+    let (b_parts, b_pre) = split(b);
+    a_parts.cmp(&b_parts).then_with(|| match (a_pre, b_pre) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a_pre), Some(b_pre)) => {
+            // Numeric identifiers compare numerically and sort before
+            // alphanumeric ones; a shorter list sorts first
+            let identifier = |id: &str| match id.parse::<u64>() {
+                Ok(n) => (0, n, String::new()),
+                Err(_) => (1, 0, id.to_string()),
+            };
+            a_pre
+                .split('.')
+                .map(identifier)
+                .cmp(b_pre.split('.').map(identifier))
+        }
+    })
+}
+
 impl SettingItem {
+    /// Whether this setting was introduced after `last_seen_version`
+    pub fn is_new_since(&self, last_seen_version: &str) -> bool {
+        self.since.as_deref().is_some_and(|since| {
+            compare_versions(since, last_seen_version) == std::cmp::Ordering::Greater
+        })
+    }
+
     /// Calculate the total height needed for this item (control + description + spacing)
     pub fn item_height(&self) -> u16 {
         // Height = control + description (if any) + spacing
@@ -501,6 +556,98 @@ impl ScrollItem for SettingItem {
     }
 }
 
+/// One visual row of the settings panel
+///
+/// In two-column mode, consecutive boolean toggles share a row so wide
+/// terminals don't show a single short checkbox per line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsRow {
+    /// A single item spanning the full width
+    Single(usize),
+    /// Two toggles side by side (left, right)
+    Pair(usize, usize),
+}
+
+impl SettingsRow {
+    /// Item indices in this row, left to right
+    pub fn indices(&self) -> Vec<usize> {
+        match *self {
+            SettingsRow::Single(idx) => vec![idx],
+            SettingsRow::Pair(left, right) => vec![left, right],
+        }
+    }
+
+    /// Column (0 = left, 1 = right) of an item in this row, if present
+    pub fn column_of(&self, index: usize) -> Option<usize> {
+        self.indices().iter().position(|&i| i == index)
+    }
+}
+
+/// Group a page's items into rows, pairing consecutive toggles when `two_column` is set
+pub fn build_rows(items: &[SettingItem], two_column: bool) -> Vec<SettingsRow> {
+    let is_toggle = |idx: usize| matches!(items[idx].control, SettingControl::Toggle(_));
+    let mut rows = Vec::new();
+    let mut idx = 0;
+    while idx < items.len() {
+        if two_column && idx + 1 < items.len() && is_toggle(idx) && is_toggle(idx + 1) {
+            rows.push(SettingsRow::Pair(idx, idx + 1));
+            idx += 2;
+        } else {
+            rows.push(SettingsRow::Single(idx));
+            idx += 1;
+        }
+    }
+    rows
+}
+
+/// A row bound to its page items, for use with `ScrollablePanel`
+pub struct RowItem<'a> {
+    pub row: SettingsRow,
+    pub items: &'a [SettingItem],
+}
+
+impl<'a> RowItem<'a> {
+    /// Build scrollable rows for a page's items
+    pub fn build(items: &'a [SettingItem], two_column: bool) -> Vec<Self> {
+        build_rows(items, two_column)
+            .into_iter()
+            .map(|row| RowItem { row, items })
+            .collect()
+    }
+}
+
+impl ScrollItem for RowItem<'_> {
+    fn height(&self) -> u16 {
+        self.row
+            .indices()
+            .iter()
+            .map(|&idx| self.items[idx].height())
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn focus_regions(&self) -> Vec<FocusRegion> {
+        match self.row {
+            SettingsRow::Single(idx) => self.items[idx].focus_regions(),
+            // One region per column, with the column as its id
+            SettingsRow::Pair(left, right) => [left, right]
+                .into_iter()
+                .enumerate()
+                .map(|(column, idx)| FocusRegion {
+                    id: column,
+                    y_offset: 0,
+                    height: self.items[idx].height(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Index of the row containing `index`
+pub fn row_of(rows: &[RowItem<'_>], index: usize) -> Option<usize> {
+    rows.iter().position(|r| r.row.column_of(index).is_some())
+}
+
 /// A page of settings (corresponds to a category)
 #[derive(Debug, Clone)]
 pub struct SettingsPage {
@@ -516,29 +663,100 @@ pub struct SettingsPage {
     pub subpages: Vec<SettingsPage>,
 }
 
+/// Config path holding the starred setting paths, also used as the path
+/// of the synthetic "Starred" page
+pub const STARRED_PATH: &str = "/starred_settings";
+
+impl SettingsPage {
+    /// Whether this is the synthetic page listing starred settings
+    pub fn is_starred(&self) -> bool {
+        self.path == STARRED_PATH
+    }
+}
+
+/// Setting paths in the value stored at `STARRED_PATH`
+pub fn starred_paths(value: Option<&serde_json::Value>) -> Vec<String> {
+    value
+        .and_then(|v| v.as_array())
+        .map(|paths| {
+            paths
+                .iter()
+                .filter_map(|p| p.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 /// Convert a category tree into pages with control states
+///
+/// Settings marked advanced in the schema are left out unless `show_advanced` is set.
+/// When any settings are starred, a "Starred" page listing them comes first,
+/// and the "Keybindings" page comes last.
 pub fn build_pages(
     categories: &[SettingCategory],
     config_value: &serde_json::Value,
+    show_advanced: bool,
 ) -> Vec<SettingsPage> {
-    categories
-        .iter()
-        .map(|cat| build_page(cat, config_value))
+    let starred = build_starred_page(categories, config_value);
+    starred
+        .into_iter()
+        .chain(
+            categories
+                .iter()
+                .map(|cat| build_page(cat, config_value, show_advanced)),
+        )
+        .chain(build_keybindings_page(categories, config_value))
         .collect()
 }
 
+/// Build the "Starred" page, in the order the settings were starred
+fn build_starred_page(
+    categories: &[SettingCategory],
+    config_value: &serde_json::Value,
+) -> Option<SettingsPage> {
+    fn find<'a>(categories: &'a [SettingCategory], path: &str) -> Option<&'a SettingSchema> {
+        categories.iter().find_map(|cat| {
+            cat.settings
+                .iter()
+                .find(|s| s.path == path)
+                .or_else(|| find(&cat.subcategories, path))
+        })
+    }
+
+    let items: Vec<SettingItem> = starred_paths(config_value.pointer(STARRED_PATH))
+        .iter()
+        .filter_map(|path| find(categories, path))
+        .map(|s| build_item(s, config_value))
+        .collect();
+    if items.is_empty() {
+        return None;
+    }
+    Some(SettingsPage {
+        name: "Starred".to_string(),
+        path: STARRED_PATH.to_string(),
+        description: Some("Settings starred with '*'".to_string()),
+        items,
+        subpages: Vec::new(),
+    })
+}
+
 /// Build a single page from a category
-fn build_page(category: &SettingCategory, config_value: &serde_json::Value) -> SettingsPage {
+fn build_page(
+    category: &SettingCategory,
+    config_value: &serde_json::Value,
+    show_advanced: bool,
+) -> SettingsPage {
     let items = category
         .settings
         .iter()
+        .filter(|s| show_advanced || !s.advanced)
         .map(|s| build_item(s, config_value))
         .collect();
 
     let subpages = category
         .subcategories
         .iter()
-        .map(|sub| build_page(sub, config_value))
+        .map(|sub| build_page(sub, config_value, show_advanced))
         .collect();
 
     SettingsPage {
@@ -565,7 +783,11 @@ pub fn build_item(schema: &SettingSchema, config_value: &serde_json::Value) -> S
             SettingControl::Toggle(ToggleState::new(checked, &schema.name))
         }
 
-        SettingType::Integer { minimum, maximum } => {
+        SettingType::Integer {
+            minimum,
+            maximum,
+            unit,
+        } => {
             let value = current_value
                 .and_then(|v| v.as_i64())
                 .or_else(|| schema.default.as_ref().and_then(|d| d.as_i64()))
@@ -578,6 +800,9 @@ pub fn build_item(schema: &SettingSchema, config_value: &serde_json::Value) -> S
             if let Some(max) = maximum {
                 state = state.with_max(*max);
             }
+            if let Some(unit) = unit {
+                state = state.with_unit(*unit);
+            }
             SettingControl::Number(state)
         }
 
@@ -719,6 +944,9 @@ pub fn build_item(schema: &SettingSchema, config_value: &serde_json::Value) -> S
         control,
         default: schema.default.clone(),
         modified,
+        file_path: schema.file_path,
+        since: schema.since.clone(),
+        doc_url: schema.doc_url.clone(),
     }
 }
 
@@ -737,7 +965,11 @@ pub fn build_item_from_value(
             SettingControl::Toggle(ToggleState::new(checked, &schema.name))
         }
 
-        SettingType::Integer { minimum, maximum } => {
+        SettingType::Integer {
+            minimum,
+            maximum,
+            unit,
+        } => {
             let value = current_value
                 .and_then(|v| v.as_i64())
                 .or_else(|| schema.default.as_ref().and_then(|d| d.as_i64()))
@@ -750,6 +982,9 @@ pub fn build_item_from_value(
             if let Some(max) = maximum {
                 state = state.with_max(*max);
             }
+            if let Some(unit) = unit {
+                state = state.with_unit(*unit);
+            }
             SettingControl::Number(state)
         }
 
@@ -884,13 +1119,16 @@ pub fn build_item_from_value(
         control,
         default: schema.default.clone(),
         modified,
+        file_path: schema.file_path,
+        since: schema.since.clone(),
+        doc_url: schema.doc_url.clone(),
     }
 }
 
 /// Extract the current value from a control
 pub fn control_to_value(control: &SettingControl) -> serde_json::Value {
     match control {
-        SettingControl::Toggle(state) => serde_json::Value::Bool(state.checked),
+        SettingControl::Toggle(state) => serde_json::Value::Bool(state.checked()),
 
         SettingControl::Number(state) => {
             if state.is_percentage {
@@ -936,6 +1174,8 @@ pub fn control_to_value(control: &SettingControl) -> serde_json::Value {
             serde_json::from_str(&state.value()).unwrap_or(serde_json::Value::Null)
         }
 
+        SettingControl::KeyChord(state) => serde_json::Value::String(state.key.clone()),
+
         SettingControl::Complex { .. } => serde_json::Value::Null,
     }
 }
@@ -963,6 +1203,10 @@ mod tests {
             description: Some("Check for updates".to_string()),
             setting_type: SettingType::Boolean,
             default: Some(serde_json::Value::Bool(true)),
+            advanced: false,
+            file_path: false,
+            since: None,
+            doc_url: None,
         };
 
         let config = sample_config();
@@ -972,12 +1216,61 @@ mod tests {
         assert!(item.modified); // false != true (default)
 
         if let SettingControl::Toggle(state) = &item.control {
-            assert!(!state.checked); // Current value is false
+            assert!(!state.checked()); // Current value is false
         } else {
             panic!("Expected toggle control");
         }
     }
 
+    #[test]
+    fn test_is_new_since_compares_versions_numerically() {
+        let schema = SettingSchema {
+            path: "/editor/settings_width_percent".to_string(),
+            name: "Settings Width Percent".to_string(),
+            description: None,
+            setting_type: SettingType::Integer {
+                minimum: None,
+                maximum: None,
+                unit: None,
+            },
+            default: None,
+            advanced: false,
+            file_path: false,
+            since: Some("0.1.10".to_string()),
+            doc_url: None,
+        };
+        let item = build_item(&schema, &sample_config());
+
+        assert!(item.is_new_since("0.1.9"));
+        assert!(item.is_new_since("0.0.99"));
+        assert!(!item.is_new_since("0.1.10"));
+        assert!(!item.is_new_since("0.2.0"));
+        assert!(item.is_new_since("0.1.10-rc1"));
+        assert!(!item.is_new_since("0.1.11-rc1"));
+    }
+
+    #[test]
+    fn test_compare_versions_orders_pre_releases() {
+        use std::cmp::Ordering;
+
+        assert_eq!(compare_versions("0.2.0-rc.1", "0.2.0"), Ordering::Less);
+        assert_eq!(
+            compare_versions("0.2.0-rc.2", "0.2.0-rc.10"),
+            Ordering::Less
+        );
+        assert_eq!(
+            compare_versions("0.2.0-alpha", "0.2.0-beta"),
+            Ordering::Less
+        );
+        assert_eq!(
+            compare_versions("0.2.0-rc.1", "0.2.0-rc"),
+            Ordering::Greater
+        );
+        assert_eq!(compare_versions("0.2.0-1", "0.2.0-rc"), Ordering::Less);
+        assert_eq!(compare_versions("0.2.0+build.5", "0.2.0"), Ordering::Equal);
+        assert_eq!(compare_versions("0.1.9", "0.2.0-rc.1"), Ordering::Less);
+    }
+
     #[test]
     fn test_build_number_item() {
         let schema = SettingSchema {
@@ -987,8 +1280,13 @@ mod tests {
             setting_type: SettingType::Integer {
                 minimum: Some(1),
                 maximum: Some(16),
+                unit: None,
             },
             default: Some(serde_json::Value::Number(4.into())),
+            advanced: false,
+            file_path: false,
+            since: None,
+            doc_url: None,
         };
 
         let config = sample_config();
@@ -1013,6 +1311,10 @@ mod tests {
             description: None,
             setting_type: SettingType::String,
             default: Some(serde_json::Value::String("high-contrast".to_string())),
+            advanced: false,
+            file_path: false,
+            since: None,
+            doc_url: None,
         };
 
         let config = sample_config();
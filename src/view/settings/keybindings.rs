@@ -0,0 +1,334 @@
+//! Keybindings page
+//!
+//! Lists every action of the active keymap with the key it is bound to.
+//! Focusing a row and pressing Enter captures the next key press as the
+//! action's new binding, staged as a change to the `keybindings` setting.
+//! The keymap key it replaces is unbound with a `noop` entry.
+
+use super::items::{SettingControl, SettingItem, SettingsPage};
+use super::schema::{humanize_name, SettingCategory};
+use crate::config::{Config, Keybinding};
+use crate::input::keybindings::{format_keybinding, KeyContext, KeybindingResolver};
+use crate::view::controls::FocusState;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::{HashMap, HashSet};
+
+/// Config path of the custom keybindings, also used as the path of the
+/// synthetic "Keybindings" page
+pub const KEYBINDINGS_PATH: &str = "/keybindings";
+
+/// Action of the custom entries that unbind a keymap key
+const NOOP_ACTION: &str = "noop";
+
+/// State for a row of the Keybindings page
+#[derive(Debug, Clone)]
+pub struct KeyChordState {
+    /// Action the row binds, e.g. "save"
+    pub action: String,
+    /// Context of the keymap binding ("normal" or "global")
+    pub when: String,
+    /// Bound key as displayed, e.g. "Ctrl+S"
+    pub key: String,
+    /// Config key name and modifiers of the keymap binding, unbound while
+    /// the action has a custom key
+    pub default_key: (String, Vec<String>),
+    /// Another action bound to the same key
+    pub conflict: Option<String>,
+    /// Waiting for the key press that becomes the new binding
+    pub capturing: bool,
+    /// Focus state
+    pub focus: FocusState,
+}
+
+/// Whether a binding can be shown and replaced on the Keybindings page:
+/// a single key without arguments in the normal or global context
+fn is_editable(binding: &Keybinding) -> bool {
+    binding.keys.is_empty()
+        && binding.args.is_empty()
+        && !binding.key.is_empty()
+        && matches!(
+            binding.when.as_deref(),
+            None | Some("normal") | Some("global")
+        )
+}
+
+/// Parsed form of a config key name and modifiers
+fn parse_key(key: &str, modifiers: &[String]) -> Option<(KeyCode, KeyModifiers)> {
+    KeybindingResolver::parse_key(key)
+        .map(|code| (code, KeybindingResolver::parse_modifiers(modifiers)))
+}
+
+/// Context a binding is active in, read the way the resolver reads it
+fn context(binding: &Keybinding) -> KeyContext {
+    binding
+        .when
+        .as_deref()
+        .and_then(KeyContext::from_when_clause)
+        .unwrap_or(KeyContext::Normal)
+}
+
+/// Actions each key triggers in each context: the keymap with the custom
+/// bindings laid over it, as the resolver does. A chord is listed under its
+/// first key, which a single-key binding of that key would shadow.
+fn bound_actions(
+    keymap: &[Keybinding],
+    custom: &[Keybinding],
+) -> HashMap<(KeyContext, KeyCode, KeyModifiers), HashSet<String>> {
+    let mut singles = HashMap::new();
+    let mut bound: HashMap<_, HashSet<String>> = HashMap::new();
+    for binding in keymap.iter().chain(custom) {
+        match binding.keys.first() {
+            Some(first) => {
+                if let Some((code, modifiers)) = parse_key(&first.key, &first.modifiers) {
+                    bound
+                        .entry((context(binding), code, modifiers))
+                        .or_default()
+                        .insert(binding.action.clone());
+                }
+            }
+            None => {
+                if let Some((code, modifiers)) = parse_key(&binding.key, &binding.modifiers) {
+                    singles.insert((context(binding), code, modifiers), &binding.action);
+                }
+            }
+        }
+    }
+    for (key, action) in singles {
+        if action != NOOP_ACTION {
+            bound.entry(key).or_default().insert(action.clone());
+        }
+    }
+    bound
+}
+
+/// Display form of a binding's key, e.g. "Ctrl+S"
+fn display_key(binding: &Keybinding) -> String {
+    KeybindingResolver::parse_key(&binding.key)
+        .map(|code| {
+            let modifiers = KeybindingResolver::parse_modifiers(&binding.modifiers);
+            format_keybinding(&code, &modifiers)
+        })
+        .unwrap_or_else(|| binding.key.clone())
+}
+
+/// Build the "Keybindings" page, in action name order.
+/// There is no page when the schema has no `keybindings` setting to stage
+/// changes to.
+pub fn build_keybindings_page(
+    categories: &[SettingCategory],
+    config_value: &serde_json::Value,
+) -> Option<SettingsPage> {
+    fn has_setting(categories: &[SettingCategory], path: &str) -> bool {
+        categories.iter().any(|cat| {
+            cat.settings.iter().any(|s| s.path == path) || has_setting(&cat.subcategories, path)
+        })
+    }
+    if !has_setting(categories, KEYBINDINGS_PATH) {
+        return None;
+    }
+
+    let config: Config = serde_json::from_value(config_value.clone()).ok()?;
+    let custom: HashMap<&str, &Keybinding> = config
+        .keybindings
+        .iter()
+        .filter(|b| is_editable(b))
+        .map(|b| (b.action.as_str(), b))
+        .collect();
+
+    // The first keymap binding of each action is its default
+    let keymap = config.resolve_keymap(&config.active_keybinding_map);
+    let bound = bound_actions(&keymap, &config.keybindings);
+    let mut seen = HashSet::new();
+    let mut rows: Vec<(String, KeyChordState, String)> = keymap
+        .iter()
+        .filter(|b| is_editable(b) && seen.insert(b.action.clone()))
+        .map(|binding| {
+            let current = custom
+                .get(binding.action.as_str())
+                .copied()
+                .unwrap_or(binding);
+            // Another action on the same key in the same context, or in
+            // the global context, which takes every key first
+            let row_context = context(current);
+            let conflict = parse_key(&current.key, &current.modifiers).and_then(|key| {
+                bound
+                    .iter()
+                    .filter(|((ctx, code, modifiers), _)| {
+                        (*code, *modifiers) == key
+                            && (*ctx == row_context
+                                || *ctx == KeyContext::Global
+                                || row_context == KeyContext::Global)
+                    })
+                    .flat_map(|(_, actions)| actions)
+                    .filter(|action| **action != binding.action)
+                    .map(|action| humanize_name(action))
+                    .min()
+            });
+            let state = KeyChordState {
+                action: binding.action.clone(),
+                when: binding.when.clone().unwrap_or_else(|| "normal".to_string()),
+                key: display_key(current),
+                default_key: (binding.key.clone(), binding.modifiers.clone()),
+                conflict,
+                capturing: false,
+                focus: FocusState::Normal,
+            };
+            (humanize_name(&binding.action), state, display_key(binding))
+        })
+        .collect();
+    rows.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let items = rows
+        .into_iter()
+        .map(|(name, state, default)| SettingItem {
+            path: format!("{}/{}", KEYBINDINGS_PATH, state.action),
+            name,
+            description: None,
+            modified: state.key != default,
+            control: SettingControl::KeyChord(state),
+            default: Some(serde_json::Value::String(default)),
+            file_path: false,
+            since: None,
+            doc_url: None,
+        })
+        .collect();
+    Some(SettingsPage {
+        name: "Keybindings".to_string(),
+        path: KEYBINDINGS_PATH.to_string(),
+        description: Some("Press Enter on an action, then its new key".to_string()),
+        items,
+        subpages: Vec::new(),
+    })
+}
+
+/// Config key name and modifiers for a key press, or `None` for keys that
+/// can't be bound
+pub fn key_event_binding(event: &KeyEvent) -> Option<(String, Vec<String>)> {
+    let key = match event.code {
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(c) => c.to_lowercase().to_string(),
+        KeyCode::F(n) => format!("F{}", n),
+        KeyCode::Enter => "Enter".to_string(),
+        KeyCode::Backspace => "Backspace".to_string(),
+        KeyCode::Delete => "Delete".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::BackTab => "BackTab".to_string(),
+        KeyCode::Left => "Left".to_string(),
+        KeyCode::Right => "Right".to_string(),
+        KeyCode::Up => "Up".to_string(),
+        KeyCode::Down => "Down".to_string(),
+        KeyCode::Home => "Home".to_string(),
+        KeyCode::End => "End".to_string(),
+        KeyCode::PageUp => "PageUp".to_string(),
+        KeyCode::PageDown => "PageDown".to_string(),
+        _ => return None,
+    };
+    let modifiers = [
+        (KeyModifiers::CONTROL, "ctrl"),
+        (KeyModifiers::ALT, "alt"),
+        (KeyModifiers::SHIFT, "shift"),
+    ]
+    .into_iter()
+    .filter(|(modifier, _)| event.modifiers.contains(*modifier))
+    .map(|(_, name)| name.to_string())
+    .collect();
+    Some((key, modifiers))
+}
+
+/// The `keybindings` setting with `action`'s custom binding replaced by
+/// `key`, which also unbinds the keymap's `default_key`. With `None`, or
+/// the default key itself, the custom entries go and the keymap's binding
+/// applies again. Other entries are kept as they are.
+pub fn rebind(
+    current: Option<&serde_json::Value>,
+    action: &str,
+    when: &str,
+    default_key: &(String, Vec<String>),
+    key: Option<(String, Vec<String>)>,
+) -> serde_json::Value {
+    let default = parse_key(&default_key.0, &default_key.1);
+    let mut bindings = current
+        .and_then(|v| v.as_array())
+        .cloned()
+        .unwrap_or_default();
+    bindings.retain(|value| {
+        !serde_json::from_value::<Keybinding>(value.clone()).is_ok_and(|b| {
+            let unbinds_default = b.action == NOOP_ACTION
+                && b.when.as_deref() == Some(when)
+                && parse_key(&b.key, &b.modifiers) == default;
+            (b.action == action && is_editable(&b)) || unbinds_default
+        })
+    });
+
+    let Some((key, modifiers)) =
+        key.filter(|(key, modifiers)| parse_key(key, modifiers) != default)
+    else {
+        return serde_json::Value::Array(bindings);
+    };
+    let binding = |key, modifiers, action: &str| {
+        serde_json::to_value(Keybinding {
+            key,
+            modifiers,
+            keys: Vec::new(),
+            action: action.to_string(),
+            args: HashMap::new(),
+            when: Some(when.to_string()),
+        })
+    };
+    // Later custom entries win, so the noop goes first: another action
+    // bound to the old key keeps it
+    if let Ok(value) = binding(default_key.0.clone(), default_key.1.clone(), NOOP_ACTION) {
+        bindings.insert(0, value);
+    }
+    if let Ok(value) = binding(key, modifiers, action) {
+        bindings.push(value);
+    }
+    serde_json::Value::Array(bindings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(key: &str, modifiers: &[&str]) -> (String, Vec<String>) {
+        (
+            key.to_string(),
+            modifiers.iter().map(|m| m.to_string()).collect(),
+        )
+    }
+
+    #[test]
+    fn test_rebind_unbinds_default_until_reset() {
+        let default = key("s", &["ctrl"]);
+        let bindings = rebind(None, "save", "normal", &default, Some(key("F5", &[])));
+        let actions: Vec<&str> = bindings
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|b| b["action"].as_str().unwrap())
+            .collect();
+        assert_eq!(actions, ["noop", "save"]);
+
+        // Rebinding again replaces both entries rather than adding more
+        let bindings = rebind(
+            Some(&bindings),
+            "save",
+            "normal",
+            &default,
+            Some(key("F6", &[])),
+        );
+        assert_eq!(bindings.as_array().unwrap().len(), 2);
+
+        // Going back to the keymap's key drops the custom entries
+        let reset = rebind(Some(&bindings), "save", "normal", &default, None);
+        assert_eq!(reset, serde_json::json!([]));
+        let same = rebind(
+            Some(&bindings),
+            "save",
+            "normal",
+            &default,
+            Some(key("S", &["ctrl"])),
+        );
+        assert_eq!(same, serde_json::json!([]));
+    }
+}
@@ -30,6 +30,8 @@ pub struct SettingsLayout {
     pub settings_panel_area: Option<Rect>,
     /// Scrollbar area (for drag detection)
     pub scrollbar_area: Option<Rect>,
+    /// Area of the topmost dialog drawn over the modal (confirm, entry or help)
+    pub overlay_area: Option<Rect>,
 }
 
 /// Layout info for a search result
@@ -71,6 +73,7 @@ impl SettingsLayout {
             reset_button: None,
             settings_panel_area: None,
             scrollbar_area: None,
+            overlay_area: None,
         }
     }
 
@@ -11,12 +11,16 @@
 //! - `render.rs` - Render the settings modal
 //! - `layout.rs` - Hit testing for mouse interaction
 //! - `entry_dialog.rs` - Dialog for editing complex map entries
+//! - `keybindings.rs` - Keybindings page for rebinding actions
+//! - `path_picker.rs` - Directory browser for file-path settings
 
 pub mod entry_dialog;
 pub mod input;
 pub mod items;
+pub mod keybindings;
 pub mod layout;
 pub mod mouse;
+pub mod path_picker;
 pub mod render;
 pub mod schema;
 pub mod search;
@@ -26,4 +30,6 @@ pub use entry_dialog::EntryDialogState;
 pub use layout::{SettingsHit, SettingsLayout};
 pub use render::render_settings;
 pub use search::{search_settings, SearchResult};
-pub use state::{FocusPanel, SettingsState};
+pub use state::{
+    ChangeDescription, FocusPanel, NotificationKind, SettingsNotification, SettingsState,
+};
@@ -79,9 +79,19 @@ impl Editor {
         let col = mouse_event.column;
         let row = mouse_event.row;
 
-        // When confirm dialog or help overlay is open, consume all mouse events
+        // When confirm dialog or an overlay is open, a keybinding is waiting
+        // for its new key, a prompt takes the input, or changes are
+        // previewed, consume all mouse events
         if let Some(ref state) = self.settings_state {
-            if state.showing_confirm_dialog || state.showing_help {
+            if state.showing_confirm_dialog
+                || state.showing_help
+                || state.showing_customizations
+                || state.is_capturing_key()
+                || state.path_picker.is_some()
+                || state.value_replace.is_some()
+                || state.go_to_path.is_some()
+                || state.previewing
+            {
                 return Ok(false);
             }
         }
@@ -0,0 +1,261 @@
+//! Keyboard-driven picker for settings that hold a filesystem path
+//!
+//! Lists one directory at a time. Typing filters the listing by prefix,
+//! Tab completes the typed name, and Enter opens a directory or picks a file.
+
+use std::path::{Path, PathBuf};
+
+/// An entry in the current directory listing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathEntry {
+    /// File or directory name (".." for the parent directory)
+    pub name: String,
+    /// Whether this entry is a directory
+    pub is_dir: bool,
+}
+
+/// State of the path picker
+#[derive(Debug, Clone)]
+pub struct PathPickerState {
+    /// Directory being listed
+    pub dir: PathBuf,
+    /// Entries of `dir`: ".." first, then directories, then files
+    pub entries: Vec<PathEntry>,
+    /// Typed name prefix used to filter entries
+    pub filter: String,
+    /// Index into `visible_entries()`
+    pub selected: usize,
+}
+
+impl PathPickerState {
+    /// Open the picker listing `dir`
+    pub fn new(dir: PathBuf) -> Self {
+        let mut picker = Self {
+            dir,
+            entries: Vec::new(),
+            filter: String::new(),
+            selected: 0,
+        };
+        picker.refresh();
+        picker
+    }
+
+    /// Open the picker at the directory of `current` if it exists, else at `fallback`
+    pub fn for_value(current: &str, fallback: &Path) -> Self {
+        let current = Path::new(current);
+        let dir = if current.is_dir() {
+            Some(current.to_path_buf())
+        } else {
+            current
+                .parent()
+                .filter(|p| !p.as_os_str().is_empty() && p.is_dir())
+                .map(Path::to_path_buf)
+        };
+        Self::new(dir.unwrap_or_else(|| fallback.to_path_buf()))
+    }
+
+    /// Re-read the current directory
+    fn refresh(&mut self) {
+        let mut dirs = Vec::new();
+        let mut files = Vec::new();
+        if let Ok(read_dir) = std::fs::read_dir(&self.dir) {
+            for entry in read_dir.flatten() {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                if entry.path().is_dir() {
+                    dirs.push(name);
+                } else {
+                    files.push(name);
+                }
+            }
+        }
+        dirs.sort();
+        files.sort();
+
+        self.entries.clear();
+        if self.dir.parent().is_some() {
+            self.entries.push(PathEntry {
+                name: "..".to_string(),
+                is_dir: true,
+            });
+        }
+        self.entries.extend(
+            dirs.into_iter()
+                .map(|name| PathEntry { name, is_dir: true }),
+        );
+        self.entries.extend(files.into_iter().map(|name| PathEntry {
+            name,
+            is_dir: false,
+        }));
+        self.filter.clear();
+        self.selected = 0;
+    }
+
+    /// Entries matching the typed filter
+    pub fn visible_entries(&self) -> Vec<&PathEntry> {
+        self.entries
+            .iter()
+            .filter(|e| e.name.starts_with(&self.filter))
+            .collect()
+    }
+
+    /// The highlighted entry
+    pub fn selected_entry(&self) -> Option<&PathEntry> {
+        self.visible_entries().get(self.selected).copied()
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn select_next(&mut self) {
+        let count = self.visible_entries().len();
+        if self.selected + 1 < count {
+            self.selected += 1;
+        }
+    }
+
+    /// Type a character into the filter
+    pub fn push_char(&mut self, c: char) {
+        self.filter.push(c);
+        self.selected = 0;
+    }
+
+    /// Delete a filter character, or go to the parent directory if the filter is empty
+    pub fn backspace(&mut self) {
+        if self.filter.pop().is_some() {
+            self.selected = 0;
+        } else {
+            self.open_parent();
+        }
+    }
+
+    fn open_parent(&mut self) {
+        if let Some(parent) = self.dir.parent() {
+            self.dir = parent.to_path_buf();
+            self.refresh();
+        }
+    }
+
+    /// Complete the filter to the longest prefix shared by the matching
+    /// entries. A single matching directory is opened.
+    pub fn complete(&mut self) {
+        let matches: Vec<&PathEntry> = self
+            .visible_entries()
+            .into_iter()
+            .filter(|e| e.name != "..")
+            .collect();
+        match matches.as_slice() {
+            [] => {}
+            [only] if only.is_dir => {
+                let dir = self.dir.join(&only.name);
+                self.dir = dir;
+                self.refresh();
+            }
+            [first, rest @ ..] => {
+                let mut prefix = first.name.clone();
+                for entry in rest {
+                    let common = prefix
+                        .chars()
+                        .zip(entry.name.chars())
+                        .take_while(|(a, b)| a == b)
+                        .count();
+                    prefix = prefix.chars().take(common).collect();
+                }
+                if prefix.len() > self.filter.len() {
+                    self.filter = prefix;
+                    self.selected = 0;
+                }
+            }
+        }
+    }
+
+    /// Open the highlighted directory, or return the path of the highlighted file
+    pub fn activate(&mut self) -> Option<PathBuf> {
+        let entry = self.selected_entry()?.clone();
+        if entry.name == ".." {
+            self.open_parent();
+            None
+        } else if entry.is_dir {
+            self.dir = self.dir.join(&entry.name);
+            self.refresh();
+            None
+        } else {
+            Some(self.dir.join(&entry.name))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn make_tree() -> TempDir {
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir(temp.path().join("grammars")).unwrap();
+        std::fs::create_dir(temp.path().join("themes")).unwrap();
+        std::fs::write(temp.path().join("grammars/toml.json"), "{}").unwrap();
+        std::fs::write(temp.path().join("notes.txt"), "").unwrap();
+        temp
+    }
+
+    fn names(picker: &PathPickerState) -> Vec<&str> {
+        picker
+            .visible_entries()
+            .iter()
+            .map(|e| e.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_lists_directories_before_files() {
+        let temp = make_tree();
+        let picker = PathPickerState::new(temp.path().to_path_buf());
+        assert_eq!(
+            names(&picker),
+            vec!["..", "grammars", "themes", "notes.txt"]
+        );
+    }
+
+    #[test]
+    fn test_navigate_into_directory_and_pick_file() {
+        let temp = make_tree();
+        let mut picker = PathPickerState::new(temp.path().to_path_buf());
+
+        picker.select_next(); // grammars
+        assert_eq!(picker.activate(), None);
+        assert_eq!(picker.dir, temp.path().join("grammars"));
+
+        picker.select_next(); // toml.json
+        assert_eq!(
+            picker.activate(),
+            Some(temp.path().join("grammars/toml.json"))
+        );
+    }
+
+    #[test]
+    fn test_tab_completes_prefix_and_opens_unique_directory() {
+        let temp = make_tree();
+        let mut picker = PathPickerState::new(temp.path().to_path_buf());
+
+        picker.push_char('g');
+        picker.complete();
+        assert_eq!(picker.dir, temp.path().join("grammars"));
+        assert!(picker.filter.is_empty());
+
+        // Backspace with an empty filter goes back up
+        picker.backspace();
+        assert_eq!(picker.dir, temp.path());
+    }
+
+    #[test]
+    fn test_for_value_starts_in_parent_of_current_file() {
+        let temp = make_tree();
+        let file = temp.path().join("grammars/toml.json");
+        let picker = PathPickerState::for_value(&file.to_string_lossy(), Path::new("/"));
+        assert_eq!(picker.dir, temp.path().join("grammars"));
+
+        let picker = PathPickerState::for_value("", temp.path());
+        assert_eq!(picker.dir, temp.path());
+    }
+}
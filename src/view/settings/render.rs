@@ -4,10 +4,10 @@
 
 use rust_i18n::t;
 
-use super::items::SettingControl;
+use super::items::{RowItem, SettingControl, SettingsRow};
 use super::layout::{SettingsHit, SettingsLayout};
 use super::search::SearchResult;
-use super::state::SettingsState;
+use super::state::{ChangeDescription, NotificationKind, SettingsNotification, SettingsState};
 use crate::view::controls::{
     render_dropdown_aligned, render_number_input_aligned, render_text_input_aligned,
     render_toggle_aligned, DropdownColors, MapColors, NumberInputColors, TextInputColors,
@@ -19,6 +19,10 @@ use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 use ratatui::Frame;
+use unicode_width::UnicodeWidthStr;
+
+/// Minimum terminal width for laying out toggles in two columns
+const TWO_COLUMN_MIN_WIDTH: u16 = 120;
 
 /// Build spans for a text line with selection highlighting
 ///
@@ -109,9 +113,13 @@ pub fn render_settings(
     state: &mut SettingsState,
     theme: &Theme,
 ) -> SettingsLayout {
-    // Calculate modal size (80% of screen width, 90% height to fill most of available space)
-    let modal_width = (area.width * 80 / 100).min(100);
-    let modal_height = area.height * 90 / 100;
+    // Calculate modal size from the configured percentages (default 80% width, 90% height)
+    let (width_percent, height_percent) = state.modal_size_percent;
+    // In u32, since a wide terminal times the percentage overflows u16
+    let modal_width =
+        (u32::from(area.width) * u32::from(width_percent.clamp(10, 100)) / 100).min(100) as u16;
+    let modal_height =
+        (u32::from(area.height) * u32::from(height_percent.clamp(10, 100)) / 100) as u16;
     let modal_x = (area.width.saturating_sub(modal_width)) / 2;
     let modal_y = (area.height.saturating_sub(modal_height)) / 2;
 
@@ -120,7 +128,9 @@ pub fn render_settings(
     // Clear the modal area and draw border
     frame.render_widget(Clear, modal_area);
 
-    let title = if state.has_changes() {
+    let title = if state.previewing {
+        format!(" Settings [{}] • (previewing) ", state.target_layer_name())
+    } else if state.has_changes() {
         format!(" Settings [{}] • (modified) ", state.target_layer_name())
     } else {
         format!(" Settings [{}] ", state.target_layer_name())
@@ -142,9 +152,16 @@ pub fn render_settings(
     );
 
     // Render search header if search is active
-    let (search_header_height, content_area) = if state.search_active {
+    let (search_header_height, content_area) = if state.search_active
+        || state.value_replace.is_some()
+        || state.go_to_path.is_some()
+    {
         let search_area = Rect::new(inner_area.x, inner_area.y, inner_area.width, 2);
-        render_search_header(frame, search_area, state, theme);
+        match (&state.value_replace, &state.go_to_path) {
+            (Some(replace), _) => render_value_replace_header(frame, search_area, replace, theme),
+            (None, Some(path)) => render_go_to_path_header(frame, search_area, state, path, theme),
+            (None, None) => render_search_header(frame, search_area, state, theme),
+        }
         (
             2,
             Rect::new(
@@ -200,6 +217,11 @@ pub fn render_settings(
     // Render footer with buttons
     render_footer(frame, modal_area, state, theme, &mut layout);
 
+    // Transient notification just above the footer separator
+    if let Some(notification) = state.current_notification() {
+        render_notification(frame, modal_area, notification, theme);
+    }
+
     // Determine the topmost dialog layer and apply dimming to layers below
     let has_confirm = state.showing_confirm_dialog;
     let has_entry = state.showing_entry_dialog();
@@ -212,7 +234,7 @@ pub fn render_settings(
         if !has_entry && !has_help {
             crate::view::dimming::apply_dimming(frame, modal_area);
         }
-        render_confirm_dialog(frame, modal_area, state, theme);
+        render_confirm_dialog(frame, modal_area, state, theme, &mut layout);
     }
 
     // Render entry detail dialog if showing
@@ -221,14 +243,26 @@ pub fn render_settings(
         if !has_help {
             crate::view::dimming::apply_dimming(frame, modal_area);
         }
-        render_entry_dialog(frame, modal_area, state, theme);
+        render_entry_dialog(frame, modal_area, state, theme, &mut layout);
+    }
+
+    // Render the "what changed" panel on first open
+    if state.showing_customizations && !has_help {
+        crate::view::dimming::apply_dimming(frame, modal_area);
+        render_customizations_panel(frame, modal_area, state, theme, &mut layout);
     }
 
     // Render help overlay if showing
     if has_help {
         // Help is topmost, dim everything below
         crate::view::dimming::apply_dimming(frame, modal_area);
-        render_help_overlay(frame, modal_area, theme);
+        render_help_overlay(frame, modal_area, theme, &mut layout);
+    }
+
+    // Render path picker above everything else
+    if let Some(ref picker) = state.path_picker {
+        crate::view::dimming::apply_dimming(frame, modal_area);
+        render_path_picker(frame, modal_area, picker, theme, &mut layout);
     }
 
     layout
@@ -308,6 +342,9 @@ struct RenderContext<'a> {
     settings_focused: bool,
     hover_hit: Option<SettingsHit>,
     layer_sources: &'a std::collections::HashMap<String, crate::config_io::ConfigLayer>,
+    last_seen_version: Option<&'a str>,
+    /// Paths of starred settings, marked with a star
+    starred: &'a [String],
 }
 
 /// Render the settings panel for the current category
@@ -327,12 +364,46 @@ fn render_settings_panel(
     let mut y = area.y;
     let header_start_y = y;
 
-    // Page title
+    // Page title, or the breadcrumb of the focused setting
     let title_style = Style::default()
         .fg(theme.menu_active_fg)
         .add_modifier(Modifier::BOLD);
-    let title = Line::from(Span::styled(&page.name, title_style));
+    let crumbs = if state.focus_panel == super::state::FocusPanel::Settings {
+        state.breadcrumb()
+    } else {
+        Vec::new()
+    };
+    let title = match crumbs.split_last() {
+        Some((setting, parents)) => {
+            let parent_style = Style::default().fg(theme.line_number_fg);
+            let mut spans: Vec<Span> = parents
+                .iter()
+                .flat_map(|crumb| {
+                    [
+                        Span::styled(crumb.clone(), parent_style),
+                        Span::styled(" › ", parent_style),
+                    ]
+                })
+                .collect();
+            spans.push(Span::styled(setting.clone(), title_style));
+            Line::from(spans)
+        }
+        None => Line::from(Span::styled(page.name.clone(), title_style)),
+    };
+    let title_width = title.width() as u16;
     frame.render_widget(Paragraph::new(title), Rect::new(area.x, y, area.width, 1));
+
+    // Position of the focused setting, e.g. "12/87", at the right end
+    if let Some((index, total)) = state.item_position() {
+        let label = format!("{}/{}", index, total);
+        let label_width = label.width() as u16;
+        if title_width + label_width < area.width {
+            frame.render_widget(
+                Paragraph::new(label).style(Style::default().fg(theme.line_number_fg)),
+                Rect::new(area.x + area.width - label_width, y, label_width, 1),
+            );
+        }
+    }
     y += 1;
 
     // Page description
@@ -354,18 +425,25 @@ fn render_settings_panel(
     // Calculate available height for items
     let available_height = area.height.saturating_sub(header_height as u16 + 1);
 
+    // Pair up toggles when there's room for two columns
+    state.two_column = frame.area().width >= TWO_COLUMN_MIN_WIDTH;
+
     // Update scroll panel with current viewport and content
     let page = state.pages.get(state.selected_category).unwrap();
+    let rows = RowItem::build(&page.items, state.two_column);
     state.scroll_panel.set_viewport(available_height);
-    state.scroll_panel.update_content_height(&page.items);
+    state.scroll_panel.update_content_height(&rows);
 
     // Extract state needed for rendering (to avoid borrow issues with scroll_panel)
     use super::state::FocusPanel;
+    let starred = state.starred_paths();
     let render_ctx = RenderContext {
         selected_item: state.selected_item,
         settings_focused: state.focus_panel == FocusPanel::Settings,
         hover_hit: state.hover_hit.clone(),
         layer_sources: &state.layer_sources,
+        last_seen_version: state.last_seen_version.as_deref(),
+        starred: &starred,
     };
 
     // Area for items (below header)
@@ -391,35 +469,72 @@ fn render_settings_panel(
         })
         .max();
 
+    // Toggles sharing a row align among themselves
+    let toggle_label_width = page
+        .items
+        .iter()
+        .filter_map(|item| match &item.control {
+            SettingControl::Toggle(s) => Some(s.label.len() as u16),
+            _ => None,
+        })
+        .max();
+
     // Use ScrollablePanel to render items with automatic scroll handling
+    let rows = RowItem::build(&page.items, state.two_column);
     let panel_layout = state.scroll_panel.render(
         frame,
         items_area,
-        &page.items,
-        |frame, info, item| {
-            render_setting_item_pure(
-                frame,
-                info.area,
-                item,
-                info.index,
-                info.skip_top,
-                &render_ctx,
-                theme,
-                max_label_width,
-            )
+        &rows,
+        |frame, info, row| match row.row {
+            SettingsRow::Single(idx) => {
+                let item_layout = render_setting_item_pure(
+                    frame,
+                    info.area,
+                    &row.items[idx],
+                    idx,
+                    info.skip_top,
+                    &render_ctx,
+                    theme,
+                    max_label_width,
+                );
+                vec![(idx, info.area, item_layout)]
+            }
+            SettingsRow::Pair(left, right) => {
+                let left_width = info.area.width / 2;
+                let left_area = Rect::new(info.area.x, info.area.y, left_width, info.area.height);
+                let right_area = Rect::new(
+                    info.area.x + left_width,
+                    info.area.y,
+                    info.area.width - left_width,
+                    info.area.height,
+                );
+                [(left, left_area), (right, right_area)]
+                    .into_iter()
+                    .map(|(idx, area)| {
+                        let item_layout = render_setting_item_pure(
+                            frame,
+                            area,
+                            &row.items[idx],
+                            idx,
+                            info.skip_top,
+                            &render_ctx,
+                            theme,
+                            toggle_label_width,
+                        );
+                        (idx, area, item_layout)
+                    })
+                    .collect()
+            }
         },
         theme,
     );
 
     // Transfer item layouts to SettingsLayout
     let page = state.pages.get(state.selected_category).unwrap();
-    for item_info in panel_layout.item_layouts {
-        layout.add_item(
-            item_info.index,
-            page.items[item_info.index].path.clone(),
-            item_info.area,
-            item_info.layout,
-        );
+    for row_info in panel_layout.item_layouts {
+        for (idx, area, item_layout) in row_info.layout {
+            layout.add_item(idx, page.items[idx].path.clone(), area, item_layout);
+        }
     }
 
     // Track the settings panel area for scroll hit testing
@@ -540,13 +655,28 @@ fn render_setting_item_pure(
         );
     }
 
+    // Star marker next to the focus indicator
+    if skip_top == 0 && ctx.starred.contains(&item.path) {
+        frame.render_widget(
+            Paragraph::new("★").style(Style::default().fg(theme.diagnostic_warning_fg)),
+            Rect::new(area.x + 1, area.y, 1, 1),
+        );
+    }
+
+    // Badge settings added since the settings were last saved
+    let is_new = ctx
+        .last_seen_version
+        .is_some_and(|version| item.is_new_since(version));
+    let badge_width = if is_new && skip_top == 0 { 5 } else { 0 };
+
     // Calculate control height and area (offset by focus indicator)
     let control_height = item.control.control_height();
     let visible_control_height = control_height.saturating_sub(skip_top);
     let control_area = Rect::new(
         area.x + focus_indicator_width,
         area.y,
-        area.width.saturating_sub(focus_indicator_width),
+        area.width
+            .saturating_sub(focus_indicator_width + badge_width),
         visible_control_height.min(area.height),
     );
 
@@ -562,6 +692,17 @@ fn render_setting_item_pure(
         label_width.map(|w| w.saturating_sub(focus_indicator_width)),
     );
 
+    if badge_width > 0 && area.width > focus_indicator_width + badge_width {
+        let badge_style = Style::default()
+            .fg(theme.popup_bg)
+            .bg(theme.diagnostic_info_fg)
+            .add_modifier(Modifier::BOLD);
+        frame.render_widget(
+            Paragraph::new("NEW").style(badge_style),
+            Rect::new(area.x + area.width - badge_width + 1, area.y, 3, 1),
+        );
+    }
+
     // Render description below the control (if visible and exists)
     // Description is also offset by focus_indicator_width to align with control
     let desc_start_row = control_height.saturating_sub(skip_top);
@@ -736,6 +877,39 @@ fn render_control(
             render_json_control(frame, area, state, name, modified, skip_rows, theme)
         }
 
+        SettingControl::KeyChord(state) => {
+            if skip_rows > 0 {
+                return ControlLayoutInfo::Complex;
+            }
+            let label_style = Style::default().fg(theme.editor_fg);
+            let modified_indicator = if modified { "• " } else { "" };
+
+            let mut spans = vec![Span::styled(
+                format!("{}{}: ", modified_indicator, name),
+                label_style,
+            )];
+            if state.capturing {
+                spans.push(Span::styled(
+                    "Press a key… (Esc to cancel)",
+                    Style::default().fg(theme.line_number_fg),
+                ));
+            } else {
+                spans.push(Span::styled(
+                    state.key.clone(),
+                    Style::default().fg(theme.help_key_fg),
+                ));
+                if let Some(other) = &state.conflict {
+                    spans.push(Span::styled(
+                        format!("  ⚠ also bound to {}", other),
+                        Style::default().fg(theme.diagnostic_warning_fg),
+                    ));
+                }
+            }
+
+            frame.render_widget(Paragraph::new(Line::from(spans)), area);
+            ControlLayoutInfo::Complex
+        }
+
         SettingControl::Complex { type_name } => {
             if skip_rows > 0 {
                 return ControlLayoutInfo::Complex;
@@ -1414,6 +1588,63 @@ pub enum ControlLayoutInfo {
     Complex,
 }
 
+/// Pending edits older than this are highlighted in the footer
+const UNSAVED_STALE_AFTER: std::time::Duration = std::time::Duration::from_secs(5 * 60);
+
+/// Format a duration as a coarse age, e.g. "42 seconds" or "3 minutes"
+fn format_age(age: std::time::Duration) -> String {
+    let secs = age.as_secs();
+    let (count, unit) = match secs {
+        0..=59 => (secs, "second"),
+        60..=3599 => (secs / 60, "minute"),
+        _ => (secs / 3600, "hour"),
+    };
+    if count == 1 {
+        format!("1 {}", unit)
+    } else {
+        format!("{} {}s", count, unit)
+    }
+}
+
+/// Render a one-line notification right-aligned above the footer separator
+fn render_notification(
+    frame: &mut Frame,
+    modal_area: Rect,
+    notification: &SettingsNotification,
+    theme: &Theme,
+) {
+    let (fg, prefix) = match notification.kind {
+        NotificationKind::Progress => (theme.diagnostic_info_fg, "… "),
+        NotificationKind::Info => (theme.diagnostic_info_fg, "ℹ "),
+        NotificationKind::Success => (theme.diagnostic_hint_fg, "✓ "),
+        NotificationKind::Error => (theme.diagnostic_error_fg, "✗ "),
+    };
+    let text = format!(" {}{} ", prefix, notification.message);
+    let max_width = modal_area.width.saturating_sub(4) as usize;
+    let text: String = text.chars().take(max_width).collect();
+    let width = text.chars().count() as u16;
+    if width == 0 || modal_area.height < 6 {
+        return;
+    }
+
+    let area = Rect::new(
+        modal_area.x + modal_area.width - 2 - width,
+        modal_area.y + modal_area.height - 4,
+        width,
+        1,
+    );
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(text).style(
+            Style::default()
+                .fg(fg)
+                .bg(theme.popup_bg)
+                .add_modifier(Modifier::BOLD),
+        ),
+        area,
+    );
+}
+
 /// Render footer with action buttons
 fn render_footer(
     frame: &mut Frame,
@@ -1446,6 +1677,71 @@ fn render_footer(
         sep_area,
     );
 
+    // How long edits have been pending, or when settings were last saved,
+    // at the right end of the separator line
+    let now = state.time_source.now();
+    let age_label = match (state.unsaved_since, state.last_saved) {
+        (Some(since), _) if state.has_changes() => {
+            let age = now.saturating_duration_since(since);
+            let style = if age >= UNSAVED_STALE_AFTER {
+                Style::default()
+                    .fg(theme.diagnostic_warning_fg)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.line_number_fg)
+            };
+            Some((format!(" Modified {} ago ", format_age(age)), style))
+        }
+        (_, Some(saved)) => Some((
+            format!(
+                " Saved {} ago ",
+                format_age(now.saturating_duration_since(saved))
+            ),
+            Style::default().fg(theme.line_number_fg),
+        )),
+        _ => None,
+    };
+    let mut age_width = 0;
+    if let Some((label, style)) = age_label {
+        age_width = label.chars().count() as u16;
+        if age_width + 2 <= sep_area.width {
+            frame.render_widget(
+                Paragraph::new(label).style(style),
+                Rect::new(
+                    sep_area.x + sep_area.width - age_width - 1,
+                    sep_area.y,
+                    age_width,
+                    1,
+                ),
+            );
+        } else {
+            age_width = 0;
+        }
+    }
+
+    // Config file for the target layer, set into the separator line.
+    // Long paths keep their tail, which holds the file name.
+    if let Some(path) = state.target_config_path() {
+        let max_chars = (sep_area.width.saturating_sub(age_width) as usize).saturating_sub(4);
+        let path = path.display().to_string();
+        let char_count = path.chars().count();
+        let shown = if char_count > max_chars {
+            let tail: String = path
+                .chars()
+                .skip(char_count + 1 - max_chars.max(1))
+                .collect();
+            format!("…{}", tail)
+        } else {
+            path
+        };
+        let label = format!(" {} ", shown);
+        let label_width = (label.chars().count() as u16).min(sep_area.width.saturating_sub(1));
+        frame.render_widget(
+            Paragraph::new(label).style(Style::default().fg(theme.line_number_fg)),
+            Rect::new(sep_area.x + 1, sep_area.y, label_width, 1),
+        );
+    }
+
     // Check if footer has keyboard focus
     let footer_focused = state.focus_panel == FocusPanel::Footer;
 
@@ -1601,8 +1897,22 @@ fn render_footer(
     // Help text (between Edit button and main buttons)
     let help_x = edit_x + edit_width + 2;
     let help_width = layer_x.saturating_sub(help_x + 1);
-    let help = if state.search_active {
+    let chord_help;
+    let help = if state.previewing {
+        "Previewing changes  Enter:Keep  Esc:Revert"
+    } else if state.search_active {
         "Type to search, ↑↓:Navigate  Enter:Jump  Esc:Cancel"
+    } else if state.value_replace.is_some() {
+        "Tab:Switch field  Enter:Replace all  Esc:Cancel"
+    } else if state.go_to_path.is_some() {
+        "Tab:Complete  Enter:Go  Esc:Cancel"
+    } else if let Some(prefix) = state.pending_chord {
+        let second = match prefix {
+            'R' => "R:Reset ALL to defaults",
+            _ => "g:First item",
+        };
+        chord_help = format!("{}-  {}  Esc:Cancel", prefix, second);
+        chord_help.as_str()
     } else if footer_focused {
         "Tab:Next button  Enter:Activate  Esc:Close"
     } else {
@@ -1648,6 +1958,17 @@ fn render_search_header(frame: &mut Frame, area: Rect, state: &SettingsState, th
         format!("{} results", result_count)
     };
 
+    let scope = if state.search_values {
+        "Names and values (Tab: names only)"
+    } else {
+        "Names (Tab: also values)"
+    };
+    let count_text = if count_text.is_empty() {
+        scope.to_string()
+    } else {
+        format!("{}  ·  {}", count_text, scope)
+    };
+
     let count_style = Style::default().fg(theme.line_number_fg);
     frame.render_widget(
         Paragraph::new(count_text).style(count_style),
@@ -1655,6 +1976,87 @@ fn render_search_header(frame: &mut Frame, area: Rect, state: &SettingsState, th
     );
 }
 
+/// Render the find/replace header for bulk edits of setting values
+fn render_value_replace_header(
+    frame: &mut Frame,
+    area: Rect,
+    replace: &super::state::ValueReplaceState,
+    theme: &Theme,
+) {
+    let label_style = Style::default().fg(theme.line_number_fg);
+    let text_style = Style::default().fg(theme.popup_text_fg);
+    let cursor_style = Style::default()
+        .fg(theme.menu_highlight_fg)
+        .add_modifier(Modifier::UNDERLINED);
+    let cursor = |active: bool| Span::styled(if active { "█" } else { " " }, cursor_style);
+
+    let line = Line::from(vec![
+        Span::styled("Find in values: ", label_style),
+        Span::styled(&replace.find, text_style),
+        cursor(!replace.editing_replace),
+        Span::styled("  Replace with: ", label_style),
+        Span::styled(&replace.replace, text_style),
+        cursor(replace.editing_replace),
+    ]);
+    frame.render_widget(
+        Paragraph::new(line),
+        Rect::new(area.x, area.y, area.width, 1),
+    );
+
+    let count_text = match replace.matches {
+        _ if replace.find.is_empty() => String::new(),
+        0 => "No matching values".to_string(),
+        1 => "1 matching setting".to_string(),
+        n => format!("{} matching settings", n),
+    };
+    frame.render_widget(
+        Paragraph::new(count_text).style(label_style),
+        Rect::new(area.x, area.y + 1, area.width, 1),
+    );
+}
+
+/// Render the go-to-path input, with the rest of the first completion
+/// dimmed after the cursor and the matching paths below
+fn render_go_to_path_header(
+    frame: &mut Frame,
+    area: Rect,
+    state: &SettingsState,
+    path: &str,
+    theme: &Theme,
+) {
+    let label_style = Style::default().fg(theme.line_number_fg);
+    let text_style = Style::default().fg(theme.popup_text_fg);
+    let cursor_style = Style::default()
+        .fg(theme.menu_highlight_fg)
+        .add_modifier(Modifier::UNDERLINED);
+
+    let completions = state.go_to_path_completions();
+    let hint = completions
+        .first()
+        .and_then(|first| first.strip_prefix(path))
+        .unwrap_or_default();
+    let line = Line::from(vec![
+        Span::styled("Go to: ", label_style),
+        Span::styled(path, text_style),
+        Span::styled("█", cursor_style),
+        Span::styled(hint, label_style),
+    ]);
+    frame.render_widget(
+        Paragraph::new(line),
+        Rect::new(area.x, area.y, area.width, 1),
+    );
+
+    let matches = match completions.len() {
+        0 => "No matching settings".to_string(),
+        1 => completions[0].clone(),
+        n => format!("{} matching settings: {}", n, completions.join("  ")),
+    };
+    frame.render_widget(
+        Paragraph::new(matches).style(label_style),
+        Rect::new(area.x, area.y + 1, area.width, 1),
+    );
+}
+
 /// Render search results with breadcrumbs
 fn render_search_results(
     frame: &mut Frame,
@@ -1703,41 +2105,84 @@ fn render_search_result_item(
         Style::default().fg(theme.popup_text_fg)
     };
 
+    let highlight_style = Style::default()
+        .fg(theme.diagnostic_warning_fg)
+        .add_modifier(Modifier::BOLD);
+
     // Build name with match highlighting
     let name_line = build_highlighted_text(
         &result.item.name,
         &result.name_matches,
         name_style,
-        Style::default()
-            .fg(theme.diagnostic_warning_fg)
-            .add_modifier(Modifier::BOLD),
+        highlight_style,
     );
     frame.render_widget(
         Paragraph::new(name_line),
         Rect::new(area.x, area.y, area.width, 1),
     );
 
-    // Second line: Breadcrumb
+    // Second line: Breadcrumb, with matches highlighted in the path
     let breadcrumb_style = Style::default()
         .fg(theme.line_number_fg)
         .add_modifier(Modifier::ITALIC);
-    let breadcrumb = format!("  {} > {}", result.breadcrumb, result.item.path);
-    let breadcrumb_line = Line::from(Span::styled(breadcrumb, breadcrumb_style));
+    let mut breadcrumb_spans = vec![Span::styled(
+        format!("  {} > ", result.breadcrumb),
+        breadcrumb_style,
+    )];
+    breadcrumb_spans.extend(
+        build_highlighted_text(
+            &result.item.path,
+            &result.path_matches,
+            breadcrumb_style,
+            highlight_style,
+        )
+        .spans,
+    );
     frame.render_widget(
-        Paragraph::new(breadcrumb_line),
+        Paragraph::new(Line::from(breadcrumb_spans)),
         Rect::new(area.x, area.y + 1, area.width, 1),
     );
 
-    // Third line: Description (if any)
-    if let Some(ref desc) = result.item.description {
+    // Third line: the matching value, else the description (if any)
+    if let Some(ref value) = result.value_match {
+        let value_style = Style::default().fg(theme.line_number_fg);
+        let line = Line::from(vec![
+            Span::styled("  Value: ", value_style),
+            Span::styled(value.clone(), highlight_style),
+        ]);
+        frame.render_widget(
+            Paragraph::new(line),
+            Rect::new(area.x, area.y + 2, area.width, 1),
+        );
+    } else if let Some(ref desc) = result.item.description {
         let desc_style = Style::default().fg(theme.line_number_fg);
-        let truncated_desc = if desc.len() > area.width as usize - 2 {
-            format!("  {}...", &desc[..area.width as usize - 5])
+        let max_chars = (area.width as usize).saturating_sub(2);
+        let mut desc_spans = vec![Span::styled("  ", desc_style)];
+        if desc.chars().count() > max_chars {
+            let truncated: String = desc.chars().take(max_chars.saturating_sub(3)).collect();
+            desc_spans.extend(
+                build_highlighted_text(
+                    &truncated,
+                    &result.description_matches,
+                    desc_style,
+                    highlight_style,
+                )
+                .spans,
+            );
+            desc_spans.push(Span::styled("...", desc_style));
         } else {
-            format!("  {}", desc)
-        };
+            desc_spans.extend(
+                build_highlighted_text(
+                    desc,
+                    &result.description_matches,
+                    desc_style,
+                    highlight_style,
+                )
+                .spans,
+            );
+        }
         frame.render_widget(
-            Paragraph::new(truncated_desc).style(desc_style),
+            Paragraph::new(Line::from(desc_spans)),
             Rect::new(area.x, area.y + 2, area.width, 1),
         );
     }
@@ -1800,10 +2245,11 @@ fn render_confirm_dialog(
     parent_area: Rect,
     state: &SettingsState,
     theme: &Theme,
+    layout: &mut SettingsLayout,
 ) {
     // Calculate dialog size
     let changes = state.get_change_descriptions();
-    let dialog_width = 50.min(parent_area.width.saturating_sub(4));
+    let dialog_width = 60.min(parent_area.width.saturating_sub(4));
     // Base height: 2 borders + 2 prompt lines + 1 separator + 1 buttons + 1 help = 7
     // Plus one line per change
     let dialog_height = (7 + changes.len() as u16)
@@ -1817,6 +2263,7 @@ fn render_confirm_dialog(
 
     // Clear and draw border
     frame.render_widget(Clear, dialog_area);
+    layout.overlay_area = Some(dialog_area);
 
     let title = format!(" {} ", t!("confirm.unsaved_changes_title"));
     let block = Block::default()
@@ -1845,19 +2292,13 @@ fn render_confirm_dialog(
     );
     y += 2;
 
-    // List changes
-    let change_style = Style::default().fg(theme.popup_text_fg);
+    // List changes as "path: old → new"
     for change in changes
         .iter()
         .take((dialog_height as usize).saturating_sub(7))
     {
-        let truncated = if change.len() > inner.width as usize - 2 {
-            format!("• {}...", &change[..inner.width as usize - 5])
-        } else {
-            format!("• {}", change)
-        };
         frame.render_widget(
-            Paragraph::new(truncated).style(change_style),
+            Paragraph::new(change_line(change, inner.width as usize, theme)),
             Rect::new(inner.x, y, inner.width, 1),
         );
         y += 1;
@@ -1917,6 +2358,124 @@ fn render_confirm_dialog(
     );
 }
 
+/// Render the panel listing settings whose saved value differs from the
+/// default, shown the first time settings are opened
+fn render_customizations_panel(
+    frame: &mut Frame,
+    parent_area: Rect,
+    state: &SettingsState,
+    theme: &Theme,
+    layout: &mut SettingsLayout,
+) {
+    let customized = state.customized_settings();
+    let dialog_width = 60.min(parent_area.width.saturating_sub(4));
+    // 2 borders + prompt + blank line + blank line + help = 6, plus one line per setting
+    let dialog_height = (6 + customized.len() as u16)
+        .min(20)
+        .min(parent_area.height.saturating_sub(4));
+
+    let dialog_x = parent_area.x + (parent_area.width.saturating_sub(dialog_width)) / 2;
+    let dialog_y = parent_area.y + (parent_area.height.saturating_sub(dialog_height)) / 2;
+    let dialog_area = Rect::new(dialog_x, dialog_y, dialog_width, dialog_height);
+
+    frame.render_widget(Clear, dialog_area);
+    layout.overlay_area = Some(dialog_area);
+
+    let block = Block::default()
+        .title(" What Changed ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.menu_highlight_fg))
+        .style(Style::default().bg(theme.popup_bg));
+    frame.render_widget(block, dialog_area);
+
+    let inner = Rect::new(
+        dialog_area.x + 2,
+        dialog_area.y + 1,
+        dialog_area.width.saturating_sub(4),
+        dialog_area.height.saturating_sub(2),
+    );
+
+    let mut y = inner.y;
+    frame.render_widget(
+        Paragraph::new("These settings differ from their defaults:")
+            .style(Style::default().fg(theme.popup_text_fg)),
+        Rect::new(inner.x, y, inner.width, 1),
+    );
+    y += 2;
+
+    // Each line reads "path: default → saved"
+    for change in customized
+        .iter()
+        .take((dialog_height as usize).saturating_sub(6))
+    {
+        frame.render_widget(
+            Paragraph::new(change_line(change, inner.width as usize, theme)),
+            Rect::new(inner.x, y, inner.width, 1),
+        );
+        y += 1;
+    }
+
+    frame.render_widget(
+        Paragraph::new("Press any key to continue")
+            .style(Style::default().fg(theme.line_number_fg)),
+        Rect::new(
+            inner.x,
+            inner.y + inner.height.saturating_sub(1),
+            inner.width,
+            1,
+        ),
+    );
+}
+
+/// Build one line of the unsaved-changes list, with the old value in the
+/// error color and the new one in the success color. Values that don't fit
+/// in `width` columns are cut short with an ellipsis.
+fn change_line(change: &ChangeDescription, width: usize, theme: &Theme) -> Line<'static> {
+    const ARROW: &str = " → ";
+    let old = change.old.as_deref().unwrap_or("unset");
+    let text_style = Style::default().fg(theme.popup_text_fg);
+
+    // "• " + path + ": " take what they need, leaving at least a few columns per value
+    let fixed = 2 + 2 + ARROW.chars().count();
+    let path_budget = width.saturating_sub(fixed + 8);
+    let path = truncate_with_ellipsis(&change.path, path_budget);
+    let mut remaining = width.saturating_sub(fixed + path.chars().count());
+
+    // Split the rest between the two values, letting a short one give its
+    // unused room to the other
+    let old_len = old.chars().count();
+    let new_len = change.new.chars().count();
+    let half = remaining / 2;
+    let old_budget = if new_len < remaining - half {
+        remaining.saturating_sub(new_len)
+    } else {
+        half
+    };
+    let old = truncate_with_ellipsis(old, old_budget.min(old_len));
+    remaining = remaining.saturating_sub(old.chars().count());
+    let new = truncate_with_ellipsis(&change.new, remaining);
+
+    Line::from(vec![
+        Span::styled(format!("• {}: ", path), text_style),
+        Span::styled(old, Style::default().fg(theme.diagnostic_error_fg)),
+        Span::styled(ARROW, text_style),
+        Span::styled(new, Style::default().fg(theme.diagnostic_hint_fg)),
+    ])
+}
+
+/// Shorten `text` to at most `max` characters, ending in "…" when cut
+fn truncate_with_ellipsis(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        text.to_string()
+    } else if max == 0 {
+        String::new()
+    } else {
+        let mut cut: String = text.chars().take(max - 1).collect();
+        cut.push('…');
+        cut
+    }
+}
+
 /// Render the entry detail dialog for editing Language/LSP/Keybinding entries
 ///
 /// Now uses the same SettingItem/SettingControl infrastructure as the main settings UI,
@@ -1926,6 +2485,7 @@ fn render_entry_dialog(
     parent_area: Rect,
     state: &mut SettingsState,
     theme: &Theme,
+    layout: &mut SettingsLayout,
 ) {
     let Some(dialog) = state.entry_dialog_mut() else {
         return;
@@ -1941,6 +2501,7 @@ fn render_entry_dialog(
 
     // Clear and draw border
     frame.render_widget(Clear, dialog_area);
+    layout.overlay_area = Some(dialog_area);
 
     let title = format!(" {} ", dialog.title);
 
@@ -2161,7 +2722,12 @@ fn render_entry_dialog(
 }
 
 /// Render the help overlay showing keyboard shortcuts
-fn render_help_overlay(frame: &mut Frame, parent_area: Rect, theme: &Theme) {
+fn render_help_overlay(
+    frame: &mut Frame,
+    parent_area: Rect,
+    theme: &Theme,
+    layout: &mut SettingsLayout,
+) {
     // Define the help content
     let help_items = [
         (
@@ -2170,6 +2736,8 @@ fn render_help_overlay(frame: &mut Frame, parent_area: Rect, theme: &Theme) {
                 ("↑ / ↓", "Move up/down"),
                 ("Tab", "Switch between categories and settings"),
                 ("Enter", "Activate/toggle setting"),
+                ("g g", "Go to first item"),
+                ("G", "Go to last item"),
             ],
         ),
         (
@@ -2179,12 +2747,27 @@ fn render_help_overlay(frame: &mut Frame, parent_area: Rect, theme: &Theme) {
                 ("Esc", "Cancel search"),
                 ("↑ / ↓", "Navigate results"),
                 ("Enter", "Jump to result"),
+                (":", "Go to setting by path"),
+            ],
+        ),
+        (
+            "Lists",
+            vec![
+                ("Alt+↑ / Alt+↓", "Move item up/down"),
+                ("Delete", "Remove item"),
             ],
         ),
         (
             "Actions",
             vec![
                 ("Ctrl+S", "Save settings"),
+                ("Ctrl+P", "Preview changes (Enter keeps)"),
+                ("Ctrl+R", "Replace text in setting values"),
+                ("a", "Show/hide advanced settings"),
+                ("*", "Star/unstar setting"),
+                ("R R", "Reset all settings to defaults"),
+                ("o", "Show documentation link"),
+                ("u", "Check for updates now"),
                 ("Esc", "Close settings"),
                 ("?", "Toggle this help"),
             ],
@@ -2193,7 +2776,7 @@ fn render_help_overlay(frame: &mut Frame, parent_area: Rect, theme: &Theme) {
 
     // Calculate dialog size
     let dialog_width = 50.min(parent_area.width.saturating_sub(4));
-    let dialog_height = 20.min(parent_area.height.saturating_sub(4));
+    let dialog_height = 32.min(parent_area.height.saturating_sub(4));
 
     // Center the dialog
     let dialog_x = parent_area.x + (parent_area.width.saturating_sub(dialog_width)) / 2;
@@ -2202,6 +2785,7 @@ fn render_help_overlay(frame: &mut Frame, parent_area: Rect, theme: &Theme) {
 
     // Clear and draw border
     frame.render_widget(Clear, dialog_area);
+    layout.overlay_area = Some(dialog_area);
 
     let block = Block::default()
         .title(" Keyboard Shortcuts ")
@@ -2267,10 +2851,132 @@ fn render_help_overlay(frame: &mut Frame, parent_area: Rect, theme: &Theme) {
     );
 }
 
+/// Render the path picker for file-path settings
+fn render_path_picker(
+    frame: &mut Frame,
+    parent_area: Rect,
+    picker: &super::path_picker::PathPickerState,
+    theme: &Theme,
+    layout: &mut SettingsLayout,
+) {
+    let dialog_width = 60.min(parent_area.width.saturating_sub(4));
+    let dialog_height = 20.min(parent_area.height.saturating_sub(4));
+    let dialog_x = parent_area.x + (parent_area.width.saturating_sub(dialog_width)) / 2;
+    let dialog_y = parent_area.y + (parent_area.height.saturating_sub(dialog_height)) / 2;
+    let dialog_area = Rect::new(dialog_x, dialog_y, dialog_width, dialog_height);
+
+    frame.render_widget(Clear, dialog_area);
+    layout.overlay_area = Some(dialog_area);
+
+    let block = Block::default()
+        .title(" Select File ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.menu_highlight_fg))
+        .style(Style::default().bg(theme.popup_bg));
+    frame.render_widget(block, dialog_area);
+
+    let inner = Rect::new(
+        dialog_area.x + 2,
+        dialog_area.y + 1,
+        dialog_area.width.saturating_sub(4),
+        dialog_area.height.saturating_sub(2),
+    );
+    if inner.height < 4 {
+        return;
+    }
+
+    // Current directory, keeping the tail visible when it is too long
+    let dir = picker.dir.to_string_lossy();
+    let max = inner.width as usize;
+    let dir_display = if dir.chars().count() > max {
+        let tail: String = dir
+            .chars()
+            .skip(dir.chars().count() - max.saturating_sub(1))
+            .collect();
+        format!("…{}", tail)
+    } else {
+        dir.into_owned()
+    };
+    frame.render_widget(
+        Paragraph::new(dir_display).style(
+            Style::default()
+                .fg(theme.menu_active_fg)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Rect::new(inner.x, inner.y, inner.width, 1),
+    );
+
+    // Filter input
+    let filter_line = Line::from(vec![
+        Span::styled("Name: ", Style::default().fg(theme.line_number_fg)),
+        Span::styled(
+            format!("{}_", picker.filter),
+            Style::default().fg(theme.popup_text_fg),
+        ),
+    ]);
+    frame.render_widget(
+        Paragraph::new(filter_line),
+        Rect::new(inner.x, inner.y + 1, inner.width, 1),
+    );
+
+    // Entry list, scrolled to keep the selection visible
+    let list_top = inner.y + 2;
+    let list_height = inner.height.saturating_sub(3) as usize;
+    let entries = picker.visible_entries();
+    let scroll = picker
+        .selected
+        .saturating_sub(list_height.saturating_sub(1));
+    for (row, (idx, entry)) in entries
+        .iter()
+        .enumerate()
+        .skip(scroll)
+        .take(list_height)
+        .enumerate()
+    {
+        let selected = idx == picker.selected;
+        let marker = if selected { "> " } else { "  " };
+        let suffix = if entry.is_dir { "/" } else { "" };
+        let style = if selected {
+            Style::default().fg(theme.menu_fg).bg(theme.selection_bg)
+        } else if entry.is_dir {
+            Style::default().fg(theme.diagnostic_info_fg)
+        } else {
+            Style::default().fg(theme.popup_text_fg)
+        };
+        frame.render_widget(
+            Paragraph::new(format!("{}{}{}", marker, entry.name, suffix)).style(style),
+            Rect::new(inner.x, list_top + row as u16, inner.width, 1),
+        );
+    }
+
+    let hint = "↑↓:Navigate  Enter:Open/Select  Tab:Complete  Esc:Cancel";
+    frame.render_widget(
+        Paragraph::new(hint).style(Style::default().fg(theme.line_number_fg)),
+        Rect::new(inner.x, inner.y + inner.height - 1, inner.width, 1),
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_format_age() {
+        use std::time::Duration;
+        assert_eq!(format_age(Duration::from_secs(0)), "0 seconds");
+        assert_eq!(format_age(Duration::from_secs(1)), "1 second");
+        assert_eq!(format_age(Duration::from_secs(90)), "1 minute");
+        assert_eq!(format_age(Duration::from_secs(7200)), "2 hours");
+    }
+
+    #[test]
+    fn test_truncate_with_ellipsis() {
+        assert_eq!(truncate_with_ellipsis("\"dark\"", 10), "\"dark\"");
+        assert_eq!(truncate_with_ellipsis("\"high-contrast\"", 8), "\"high-c…");
+        assert_eq!(truncate_with_ellipsis("日本語テキスト", 4), "日本語…");
+        assert_eq!(truncate_with_ellipsis("abc", 0), "");
+    }
+
     // Basic compile test - actual rendering tests would need a test backend
     #[test]
     fn test_control_layout_info() {
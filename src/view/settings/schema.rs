@@ -52,6 +52,7 @@
 //! - **Plugin-friendly**: External sources can contribute enum values
 //! - **Type-safe**: Values are validated against their referenced type
 
+use crate::view::controls::NumberUnit;
 use serde::Deserialize;
 use std::collections::HashMap;
 
@@ -68,6 +69,14 @@ pub struct SettingSchema {
     pub setting_type: SettingType,
     /// Default value (as JSON)
     pub default: Option<serde_json::Value>,
+    /// Tuning/expert setting, hidden unless advanced settings are shown
+    pub advanced: bool,
+    /// Value is a filesystem path, chosen with the path picker
+    pub file_path: bool,
+    /// Editor version that introduced the setting
+    pub since: Option<String>,
+    /// Link to documentation for the setting
+    pub doc_url: Option<String>,
 }
 
 /// Type of a setting, determines which control to render
@@ -75,10 +84,11 @@ pub struct SettingSchema {
 pub enum SettingType {
     /// Boolean toggle
     Boolean,
-    /// Integer number with optional min/max
+    /// Integer number with optional min/max, and a unit for typing suffixes
     Integer {
         minimum: Option<i64>,
         maximum: Option<i64>,
+        unit: Option<NumberUnit>,
     },
     /// Floating point number
     Number {
@@ -159,6 +169,21 @@ struct RawSchema {
     /// e.g., "/command" for OnSaveAction, "/action" for Keybinding
     #[serde(rename = "x-display-field")]
     display_field: Option<String>,
+    /// Custom extension: marks a setting as advanced (hidden by default)
+    #[serde(rename = "x-advanced", default)]
+    advanced: bool,
+    /// Custom extension: marks a string setting as a filesystem path
+    #[serde(rename = "x-file-path", default)]
+    file_path: bool,
+    /// Custom extension: editor version that introduced the setting
+    #[serde(rename = "x-since")]
+    since: Option<String>,
+    /// Custom extension: link to documentation for the setting
+    #[serde(rename = "x-doc-url")]
+    doc_url: Option<String>,
+    /// Custom extension: unit of an integer setting ("bytes", "ms" or "s")
+    #[serde(rename = "x-unit")]
+    unit: Option<String>,
 }
 
 /// An entry in the x-enum-values array
@@ -334,6 +359,10 @@ fn parse_setting(
         description,
         setting_type,
         default: schema.default.clone(),
+        advanced: schema.advanced,
+        file_path: schema.file_path,
+        since: schema.since.clone(),
+        doc_url: schema.doc_url.clone(),
     }
 }
 
@@ -391,7 +420,12 @@ fn determine_type(
         Some("integer") => {
             let minimum = resolved.minimum.as_ref().and_then(|n| n.as_i64());
             let maximum = resolved.maximum.as_ref().and_then(|n| n.as_i64());
-            SettingType::Integer { minimum, maximum }
+            let unit = schema.unit.as_deref().and_then(NumberUnit::from_name);
+            SettingType::Integer {
+                minimum,
+                maximum,
+                unit,
+            }
         }
         Some("number") => {
             let minimum = resolved.minimum.as_ref().and_then(|n| n.as_f64());
@@ -477,7 +511,7 @@ fn resolve_ref<'a>(schema: &'a RawSchema, defs: &'a HashMap<String, RawSchema>)
 }
 
 /// Convert snake_case to Title Case
-fn humanize_name(name: &str) -> String {
+pub(super) fn humanize_name(name: &str) -> String {
     name.split('_')
         .map(|word| {
             let mut chars = word.chars();
@@ -584,7 +618,10 @@ mod tests {
             .iter()
             .find(|s| s.name == "Tab Size")
             .unwrap();
-        if let SettingType::Integer { minimum, maximum } = &tab_size.setting_type {
+        if let SettingType::Integer {
+            minimum, maximum, ..
+        } = &tab_size.setting_type
+        {
             assert_eq!(*minimum, Some(1));
             assert_eq!(*maximum, Some(16));
         } else {
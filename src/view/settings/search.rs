@@ -2,8 +2,9 @@
 //!
 //! Provides fuzzy search over setting names and descriptions,
 //! with support for highlighting matching categories.
+//! Current values can optionally be searched too.
 
-use super::items::{SettingItem, SettingsPage};
+use super::items::{control_to_value, SettingControl, SettingItem, SettingsPage};
 
 /// A search result with match information
 #[derive(Debug, Clone)]
@@ -22,10 +23,24 @@ pub struct SearchResult {
     pub name_matches: Vec<usize>,
     /// Character indices that matched in the description (for highlighting)
     pub description_matches: Vec<usize>,
+    /// Character indices that matched in the path (for highlighting)
+    pub path_matches: Vec<usize>,
+    /// The part of the current value that matched, when searching values
+    pub value_match: Option<String>,
 }
 
 /// Perform fuzzy search over all settings
 pub fn search_settings(pages: &[SettingsPage], query: &str) -> Vec<SearchResult> {
+    search(pages, query, false)
+}
+
+/// Search setting names like `search_settings`, and also match the query
+/// as a case-insensitive substring of each setting's current value
+pub fn search_settings_and_values(pages: &[SettingsPage], query: &str) -> Vec<SearchResult> {
+    search(pages, query, true)
+}
+
+fn search(pages: &[SettingsPage], query: &str, include_values: bool) -> Vec<SearchResult> {
     if query.is_empty() {
         return Vec::new();
     }
@@ -34,6 +49,10 @@ pub fn search_settings(pages: &[SettingsPage], query: &str) -> Vec<SearchResult>
     let mut results = Vec::new();
 
     for (page_index, page) in pages.iter().enumerate() {
+        // Starred settings are already found on their own page
+        if page.is_starred() {
+            continue;
+        }
         for (item_index, item) in page.items.iter().enumerate() {
             // Try to match the name
             let (name_score, name_matches) = fuzzy_match(&item.name.to_lowercase(), &query_lower);
@@ -46,10 +65,23 @@ pub fn search_settings(pages: &[SettingsPage], query: &str) -> Vec<SearchResult>
                 .unwrap_or((0, Vec::new()));
 
             // Also check path for matches
-            let (path_score, _) = fuzzy_match(&item.path.to_lowercase(), &query_lower);
-
-            // Total score is the best of the three
-            let total_score = name_score.max(desc_score).max(path_score);
+            let (path_score, path_matches) = fuzzy_match(&item.path.to_lowercase(), &query_lower);
+
+            // Matching value text scores like a good name match, and an
+            // exact value scores like an exact name
+            let value_match = if include_values {
+                find_value_match(&control_to_value(&item.control), &query_lower)
+            } else {
+                None
+            };
+            let value_score = match &value_match {
+                Some(text) if text.to_lowercase() == query_lower => 150,
+                Some(_) => 50,
+                None => 0,
+            };
+
+            // Total score is the best of the four
+            let total_score = name_score.max(desc_score).max(path_score).max(value_score);
 
             if total_score > 0 {
                 results.push(SearchResult {
@@ -60,6 +92,8 @@ pub fn search_settings(pages: &[SettingsPage], query: &str) -> Vec<SearchResult>
                     score: total_score,
                     name_matches,
                     description_matches: desc_matches,
+                    path_matches,
+                    value_match,
                 });
             }
         }
@@ -167,11 +201,91 @@ pub fn matching_categories(pages: &[SettingsPage], query: &str) -> Vec<usize> {
         .collect()
 }
 
+/// Whether any string inside `value` contains `needle`
+pub fn value_contains(value: &serde_json::Value, needle: &str) -> bool {
+    match value {
+        serde_json::Value::String(s) => s.contains(needle),
+        serde_json::Value::Array(items) => items.iter().any(|v| value_contains(v, needle)),
+        serde_json::Value::Object(map) => map.values().any(|v| value_contains(v, needle)),
+        _ => false,
+    }
+}
+
+/// First scalar in `value` whose text contains `needle_lower`, ignoring case
+fn find_value_match(value: &serde_json::Value, needle_lower: &str) -> Option<String> {
+    match value {
+        // Booleans would match every toggle for "true" or "false"
+        serde_json::Value::Null | serde_json::Value::Bool(_) => None,
+        serde_json::Value::String(s) => s.to_lowercase().contains(needle_lower).then(|| s.clone()),
+        serde_json::Value::Number(n) => {
+            let text = n.to_string();
+            text.contains(needle_lower).then_some(text)
+        }
+        serde_json::Value::Array(items) => {
+            items.iter().find_map(|v| find_value_match(v, needle_lower))
+        }
+        serde_json::Value::Object(map) => {
+            map.values().find_map(|v| find_value_match(v, needle_lower))
+        }
+    }
+}
+
+/// Copy of `value` with `find` replaced by `replace` in every string it holds.
+/// Object keys are left untouched.
+pub fn replace_in_strings(
+    value: &serde_json::Value,
+    find: &str,
+    replace: &str,
+) -> serde_json::Value {
+    match value {
+        serde_json::Value::String(s) => serde_json::Value::String(s.replace(find, replace)),
+        serde_json::Value::Array(items) => serde_json::Value::Array(
+            items
+                .iter()
+                .map(|v| replace_in_strings(v, find, replace))
+                .collect(),
+        ),
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), replace_in_strings(v, find, replace)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// Whether a setting's value can be edited by find/replace. Dropdowns are
+/// excluded since their strings must stay within the allowed options.
+pub fn is_value_replaceable(item: &SettingItem) -> bool {
+    matches!(
+        item.control,
+        SettingControl::Text(_)
+            | SettingControl::TextList(_)
+            | SettingControl::Map(_)
+            | SettingControl::ObjectArray(_)
+            | SettingControl::Json(_)
+    )
+}
+
+/// Number of settings whose value contains `needle`
+pub fn count_value_matches(pages: &[SettingsPage], needle: &str) -> usize {
+    if needle.is_empty() {
+        return 0;
+    }
+    pages
+        .iter()
+        .filter(|page| !page.is_starred())
+        .flat_map(|page| &page.items)
+        .filter(|item| {
+            is_value_replaceable(item) && value_contains(&control_to_value(&item.control), needle)
+        })
+        .count()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::view::controls::ToggleState;
-    use crate::view::settings::items::SettingControl;
+    use crate::view::controls::{TextInputState, ToggleState};
 
     fn make_item(name: &str, description: Option<&str>, path: &str) -> SettingItem {
         SettingItem {
@@ -181,6 +295,9 @@ mod tests {
             control: SettingControl::Toggle(ToggleState::new(false, name)),
             default: None,
             modified: false,
+            file_path: false,
+            since: None,
+            doc_url: None,
         }
     }
 
@@ -281,6 +398,25 @@ mod tests {
         assert_eq!(results.len(), 1);
     }
 
+    #[test]
+    fn test_search_settings_match_positions() {
+        let pages = vec![make_page(
+            "General",
+            vec![make_item(
+                "Check For Updates",
+                Some("Check for new versions on quit"),
+                "/check_for_updates",
+            )],
+        )];
+
+        let results = search_settings(&pages, "chk");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name_matches, vec![0, 1, 4]);
+        assert_eq!(results[0].description_matches, vec![0, 1, 4]);
+        // Path indices include the leading '/'
+        assert_eq!(results[0].path_matches, vec![1, 2, 5]);
+    }
+
     #[test]
     fn test_matching_categories() {
         let pages = vec![
@@ -298,6 +434,44 @@ mod tests {
         assert_eq!(matches, vec![1]);
     }
 
+    #[test]
+    fn test_replace_in_strings_skips_keys_and_non_strings() {
+        let value = serde_json::json!({
+            "/opt/old": {"command": "/opt/old/bin/tool", "args": ["/opt/old/x", 3]},
+            "enabled": true
+        });
+        assert!(value_contains(&value, "/opt/old/bin"));
+        assert!(!value_contains(&value, "enabled"));
+
+        let replaced = replace_in_strings(&value, "/opt/old", "/opt/new");
+        assert_eq!(
+            replaced,
+            serde_json::json!({
+                "/opt/old": {"command": "/opt/new/bin/tool", "args": ["/opt/new/x", 3]},
+                "enabled": true
+            })
+        );
+    }
+
+    #[test]
+    fn test_search_values_matches_current_value() {
+        let mut theme = make_item("Theme", None, "/theme");
+        theme.control = SettingControl::Text(TextInputState::new("Theme").with_value("Dark+"));
+        let pages = vec![make_page(
+            "General",
+            vec![theme, make_item("Darken Inactive", None, "/darken")],
+        )];
+
+        // No name looks like the value
+        let results = search_settings(&pages, "dark+");
+        assert!(results.is_empty());
+
+        let results = search_settings_and_values(&pages, "dark+");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].item.path, "/theme");
+        assert_eq!(results[0].value_match.as_deref(), Some("Dark+"));
+    }
+
     #[test]
     fn test_search_ranking() {
         let pages = vec![make_page(
@@ -311,6 +485,7 @@ mod tests {
 
         let results = search_settings(&pages, "tab");
         assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|r| r.value_match.is_none()));
         // Exact match should be first
         assert_eq!(results[0].item.name, "Tab");
         // Then prefix match
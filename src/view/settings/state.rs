@@ -4,15 +4,25 @@
 //! and provides methods for reading/writing config values.
 
 use super::entry_dialog::EntryDialogState;
-use super::items::{control_to_value, SettingControl, SettingItem, SettingsPage};
+use super::items::{
+    build_rows, control_to_value, row_of, starred_paths, RowItem, SettingControl, SettingItem,
+    SettingsPage, STARRED_PATH,
+};
+use super::keybindings::{self, KEYBINDINGS_PATH};
 use super::layout::SettingsHit;
-use super::schema::{parse_schema, SettingCategory, SettingSchema};
-use super::search::{search_settings, SearchResult};
+use super::path_picker::PathPickerState;
+use super::schema::{humanize_name, parse_schema, SettingCategory, SettingSchema};
+use super::search::{
+    count_value_matches, is_value_replaceable, replace_in_strings, search_settings,
+    search_settings_and_values, value_contains, SearchResult,
+};
 use crate::config::Config;
 use crate::config_io::ConfigLayer;
+use crate::services::time_source::{RealTimeSource, SharedTimeSource};
 use crate::view::controls::FocusState;
 use crate::view::ui::ScrollablePanel;
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 /// Info needed to open a nested dialog (extracted before mutable borrow)
 enum NestedDialogInfo {
@@ -44,6 +54,53 @@ pub enum FocusPanel {
     Footer,
 }
 
+/// How long a finished notification stays on screen
+pub const NOTIFICATION_DURATION: std::time::Duration = std::time::Duration::from_secs(4);
+
+/// Kind of a settings notification, which decides its color and lifetime
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationKind {
+    /// Work in progress; stays until replaced by another notification
+    Progress,
+    Info,
+    Success,
+    Error,
+}
+
+/// Transient message shown in the settings modal, e.g. the result of a
+/// background update check
+#[derive(Debug, Clone)]
+pub struct SettingsNotification {
+    pub message: String,
+    pub kind: NotificationKind,
+    /// When the message was posted
+    pub posted_at: std::time::Instant,
+}
+
+/// A pending change as listed in the unsaved-changes dialog
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeDescription {
+    /// JSON pointer of the setting
+    pub path: String,
+    /// Saved value, or `None` if the setting was unset
+    pub old: Option<String>,
+    /// Pending value
+    pub new: String,
+}
+
+/// Find/replace over setting values, entered with Ctrl+R
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValueReplaceState {
+    /// Substring to look for in setting values
+    pub find: String,
+    /// Text to put in its place
+    pub replace: String,
+    /// Whether typing goes to `replace` rather than `find`
+    pub editing_replace: bool,
+    /// Number of settings whose value contains `find`
+    pub matches: usize,
+}
+
 /// The state of the settings UI
 #[derive(Debug)]
 pub struct SettingsState {
@@ -59,7 +116,8 @@ pub struct SettingsState {
     pub focus_panel: FocusPanel,
     /// Selected footer button index (0=Reset, 1=Save, 2=Cancel)
     pub footer_button_index: usize,
-    /// Pending changes (path -> new value)
+    /// Pending changes (path -> new value). `null` drops the target layer's
+    /// own value, so the setting inherits from the layers below again.
     pub pending_changes: HashMap<String, serde_json::Value>,
     /// The original config value (for detecting changes)
     original_config: serde_json::Value,
@@ -69,6 +127,8 @@ pub struct SettingsState {
     pub search_query: String,
     /// Whether search is active
     pub search_active: bool,
+    /// Whether search also matches current values (toggled with Tab while searching)
+    pub search_values: bool,
     /// Current search results
     pub search_results: Vec<SearchResult>,
     /// Selected search result index
@@ -77,8 +137,13 @@ pub struct SettingsState {
     pub showing_confirm_dialog: bool,
     /// Selected option in confirmation dialog (0=Save, 1=Discard, 2=Cancel)
     pub confirm_dialog_selection: usize,
+    /// Button last used to leave the confirmation dialog (Save or Discard),
+    /// selected by default the next time it opens
+    pub last_confirm_choice: usize,
     /// Whether the help overlay is showing
     pub showing_help: bool,
+    /// Whether the "what changed" panel listing customized settings is showing
+    pub showing_customizations: bool,
     /// Scrollable panel for settings items
     pub scroll_panel: ScrollablePanel,
     /// Sub-focus index within the selected item (for TextList/Map navigation)
@@ -100,6 +165,47 @@ pub struct SettingsState {
     /// Maps JSON pointer paths (e.g., "/editor/tab_size") to their source layer.
     /// Values not in this map come from system defaults.
     pub layer_sources: HashMap<String, ConfigLayer>,
+    /// Config file written by each editable layer, shown in the footer
+    pub config_paths: HashMap<ConfigLayer, PathBuf>,
+    /// Preferred modal size as (width, height) percentages of the terminal.
+    /// Taken from the editor config when the state is created.
+    pub modal_size_percent: (u16, u16),
+    /// Path picker open over the selected file-path setting, if any
+    pub path_picker: Option<PathPickerState>,
+    /// Directory the path picker starts in when the setting is empty
+    pub working_dir: PathBuf,
+    /// Whether settings marked advanced in the schema are listed
+    pub show_advanced: bool,
+    /// Query and selected result of the search the current item was jumped
+    /// to from, so Esc can return to the results list
+    pub search_return: Option<(String, usize)>,
+    /// Whether consecutive toggles are laid out in two columns.
+    /// Updated on render from the width of the settings panel.
+    pub two_column: bool,
+    /// Find/replace over setting values, if active
+    pub value_replace: Option<ValueReplaceState>,
+    /// Dotted setting path typed after `:` to jump to, if active
+    pub go_to_path: Option<String>,
+    /// Whether the pending changes are applied to the live UI, waiting to
+    /// be kept or reverted
+    pub previewing: bool,
+    /// Clock used for the saved/modified indicator in the footer
+    pub time_source: SharedTimeSource,
+    /// When settings were last saved, if during this session
+    pub last_saved: Option<std::time::Instant>,
+    /// When the oldest of the current pending changes was made
+    pub unsaved_since: Option<std::time::Instant>,
+    /// Editor version the settings were last saved from; settings
+    /// introduced after it are badged as new
+    pub last_seen_version: Option<String>,
+    /// First key of a multi-key chord (e.g. `g` of `gg`) awaiting its second key
+    pub pending_chord: Option<char>,
+    /// Most recent notification, hidden once it expires
+    pub notification: Option<SettingsNotification>,
+    /// Config each writable layer inherits from the layers below it
+    inherited_configs: HashMap<ConfigLayer, serde_json::Value>,
+    /// What each writable layer's config file sets itself
+    layer_configs: HashMap<ConfigLayer, serde_json::Value>,
 }
 
 impl SettingsState {
@@ -107,7 +213,7 @@ impl SettingsState {
     pub fn new(schema_json: &str, config: &Config) -> Result<Self, serde_json::Error> {
         let categories = parse_schema(schema_json)?;
         let config_value = serde_json::to_value(config)?;
-        let pages = super::items::build_pages(&categories, &config_value);
+        let pages = super::items::build_pages(&categories, &config_value, false);
 
         Ok(Self {
             categories,
@@ -121,11 +227,14 @@ impl SettingsState {
             visible: false,
             search_query: String::new(),
             search_active: false,
+            search_values: false,
             search_results: Vec::new(),
             selected_search_result: 0,
             showing_confirm_dialog: false,
             confirm_dialog_selection: 0,
+            last_confirm_choice: 0,
             showing_help: false,
+            showing_customizations: false,
             scroll_panel: ScrollablePanel::new(),
             sub_focus: None,
             editing_text: false,
@@ -134,6 +243,27 @@ impl SettingsState {
             entry_dialog_stack: Vec::new(),
             target_layer: ConfigLayer::User, // Default to user-global settings
             layer_sources: HashMap::new(),   // Populated via set_layer_sources()
+            config_paths: HashMap::new(),    // Populated via set_config_paths()
+            modal_size_percent: (
+                config.editor.settings_width_percent,
+                config.editor.settings_height_percent,
+            ),
+            path_picker: None,
+            working_dir: PathBuf::from("."), // Set via set_working_dir()
+            show_advanced: false,
+            search_return: None,
+            two_column: false,
+            value_replace: None,
+            go_to_path: None,
+            previewing: false,
+            time_source: RealTimeSource::shared(), // Set via set_time_source()
+            last_saved: None,
+            unsaved_since: None,
+            last_seen_version: config.settings_last_seen_version.clone(),
+            pending_chord: None,
+            notification: None,
+            inherited_configs: HashMap::new(), // Set via set_inherited_configs()
+            layer_configs: HashMap::new(),     // Set via set_layer_configs()
         })
     }
 
@@ -146,6 +276,7 @@ impl SettingsState {
         self.selected_item = 0;
         self.scroll_panel = ScrollablePanel::new();
         self.sub_focus = None;
+        self.search_return = None;
     }
 
     /// Hide the settings panel
@@ -153,6 +284,11 @@ impl SettingsState {
         self.visible = false;
         self.search_active = false;
         self.search_query.clear();
+        self.search_return = None;
+        self.value_replace = None;
+        self.go_to_path = None;
+        self.showing_confirm_dialog = false;
+        self.pending_chord = None;
     }
 
     /// Get the current entry dialog (top of stack), if any
@@ -186,6 +322,13 @@ impl SettingsState {
             .and_then(|page| page.items.get(self.selected_item))
     }
 
+    /// 1-based position of the selected item and the number of items on
+    /// the current page, or `None` if the page is empty
+    pub fn item_position(&self) -> Option<(usize, usize)> {
+        let total = self.current_page()?.items.len();
+        (total > 0).then(|| (self.selected_item.min(total - 1) + 1, total))
+    }
+
     /// Get the currently selected item mutably
     pub fn current_item_mut(&mut self) -> Option<&mut SettingItem> {
         self.pages
@@ -251,10 +394,12 @@ impl SettingsState {
                     })
                     .unwrap_or(false);
 
-                if !handled && self.selected_item > 0 {
-                    self.selected_item -= 1;
-                    self.sub_focus = None;
-                    self.init_map_focus(false); // entering from below
+                if !handled {
+                    if let Some(prev) = self.adjacent_row_item(false) {
+                        self.selected_item = prev;
+                        self.sub_focus = None;
+                        self.init_map_focus(false); // entering from below
+                    }
                 }
                 self.ensure_visible();
             }
@@ -289,11 +434,8 @@ impl SettingsState {
                     .unwrap_or(false);
 
                 if !handled {
-                    let can_move = self
-                        .current_page()
-                        .map_or(false, |page| self.selected_item + 1 < page.items.len());
-                    if can_move {
-                        self.selected_item += 1;
+                    if let Some(next) = self.adjacent_row_item(true) {
+                        self.selected_item = next;
                         self.sub_focus = None;
                         self.init_map_focus(true); // entering from above
                     }
@@ -309,6 +451,83 @@ impl SettingsState {
         }
     }
 
+    /// Jump to the first category or setting of the focused panel
+    pub fn select_first(&mut self) {
+        self.select_edge(false);
+    }
+
+    /// Jump to the last category or setting of the focused panel
+    pub fn select_last(&mut self) {
+        self.select_edge(true);
+    }
+
+    fn select_edge(&mut self, last: bool) {
+        match self.focus_panel {
+            FocusPanel::Categories => {
+                let target = if last {
+                    self.pages.len().saturating_sub(1)
+                } else {
+                    0
+                };
+                if target != self.selected_category {
+                    self.selected_category = target;
+                    self.selected_item = 0;
+                    self.scroll_panel = ScrollablePanel::new();
+                    self.sub_focus = None;
+                }
+            }
+            FocusPanel::Settings => {
+                let count = self.current_page().map_or(0, |page| page.items.len());
+                if count == 0 {
+                    return;
+                }
+                self.selected_item = if last { count - 1 } else { 0 };
+                self.sub_focus = None;
+                self.init_map_focus(!last);
+                self.ensure_visible();
+            }
+            FocusPanel::Footer => {}
+        }
+    }
+
+    /// Item in the same column of the row above or below the selected item
+    fn adjacent_row_item(&self, forward: bool) -> Option<usize> {
+        let page = self.current_page()?;
+        let rows = build_rows(&page.items, self.two_column);
+        let row = rows
+            .iter()
+            .position(|r| r.column_of(self.selected_item).is_some())?;
+        let column = rows[row].column_of(self.selected_item)?;
+        let target = if forward {
+            rows.get(row + 1)?
+        } else {
+            rows.get(row.checked_sub(1)?)?
+        };
+        let indices = target.indices();
+        Some(indices[column.min(indices.len() - 1)])
+    }
+
+    /// Move between the columns of a two-column row.
+    /// Returns true if the selected item shares its row with another item.
+    pub fn select_column(&mut self, right: bool) -> bool {
+        let Some(page) = self.current_page() else {
+            return false;
+        };
+        let rows = build_rows(&page.items, self.two_column);
+        let Some(row) = rows
+            .iter()
+            .find(|r| r.column_of(self.selected_item).is_some())
+        else {
+            return false;
+        };
+        let indices = row.indices();
+        if indices.len() < 2 {
+            return false;
+        }
+        self.selected_item = if right { indices[1] } else { indices[0] };
+        true
+    }
+
     /// Switch focus between panels: Categories -> Settings -> Footer -> Categories
     pub fn toggle_focus(&mut self) {
         self.focus_panel = match self.focus_panel {
@@ -316,6 +535,7 @@ impl SettingsState {
             FocusPanel::Settings => FocusPanel::Footer,
             FocusPanel::Footer => FocusPanel::Categories,
         };
+        self.search_return = None;
 
         // Reset item selection when switching to settings
         if self.focus_panel == FocusPanel::Settings
@@ -342,20 +562,83 @@ impl SettingsState {
         let selected_item = self.selected_item;
         let sub_focus = self.sub_focus;
         if let Some(page) = self.pages.get(self.selected_category) {
-            self.scroll_panel
-                .ensure_focused_visible(&page.items, selected_item, sub_focus);
+            let rows = RowItem::build(&page.items, self.two_column);
+            if let Some(row) = row_of(&rows, selected_item) {
+                self.scroll_panel
+                    .ensure_focused_visible(&rows, row, sub_focus);
+            }
         }
     }
 
     /// Record a pending change for a setting
     pub fn set_pending_change(&mut self, path: &str, value: serde_json::Value) {
-        // Check if this is the same as the original value
-        let original = self.original_config.pointer(path);
-        if original == Some(&value) {
+        // Inheriting only changes anything if the target layer sets a value
+        let unchanged = if value.is_null() {
+            self.saved_explicit_value(path).is_none()
+        } else {
+            self.original_config.pointer(path) == Some(&value)
+        };
+        self.record_pending_change(path, value, unchanged);
+    }
+
+    fn record_pending_change(&mut self, path: &str, value: serde_json::Value, unchanged: bool) {
+        if unchanged {
             self.pending_changes.remove(path);
         } else {
             self.pending_changes.insert(path.to_string(), value);
         }
+        if let Some(value) = self.current_value(path).cloned() {
+            self.sync_item_copies(path, &value);
+        }
+
+        if self.pending_changes.is_empty() {
+            self.unsaved_since = None;
+        } else if self.unsaved_since.is_none() {
+            self.unsaved_since = Some(self.time_source.now());
+        }
+    }
+
+    /// Keep other copies of a changed setting in step with the current one.
+    /// A starred setting appears both on its own page and on the Starred page.
+    fn sync_item_copies(&mut self, path: &str, value: &serde_json::Value) {
+        let current = (self.selected_category, self.selected_item);
+        // Map entries and array items are staged below the setting's path;
+        // the current item's control already holds the whole new value
+        let source = self
+            .current_item()
+            .filter(|item| path.starts_with(&format!("{}/", item.path)))
+            .map(|item| (item.path.clone(), item.control.clone(), item.modified));
+
+        for (page_idx, page) in self.pages.iter_mut().enumerate() {
+            for (item_idx, item) in page.items.iter_mut().enumerate() {
+                if (page_idx, item_idx) == current {
+                    continue;
+                }
+                if item.path == path {
+                    update_control_from_value(&mut item.control, value);
+                    item.modified = item.default.as_ref() != Some(value);
+                } else if let Some((source_path, control, modified)) = &source {
+                    if &item.path == source_path {
+                        item.control = control.clone();
+                        item.modified = *modified;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Value of a setting when the dialog was opened
+    pub fn original_value(&self, path: &str) -> Option<&serde_json::Value> {
+        self.original_config.pointer(path)
+    }
+
+    /// Value of a setting including any pending change
+    pub fn current_value(&self, path: &str) -> Option<&serde_json::Value> {
+        match self.pending_changes.get(path) {
+            Some(serde_json::Value::Null) => self.inherited_value(path),
+            Some(value) => Some(value),
+            None => self.original_value(path),
+        }
     }
 
     /// Check if there are unsaved changes
@@ -367,8 +650,10 @@ impl SettingsState {
     pub fn apply_changes(&self, config: &Config) -> Result<Config, serde_json::Error> {
         let mut config_value = serde_json::to_value(config)?;
 
-        for (path, value) in &self.pending_changes {
-            if let Some(target) = config_value.pointer_mut(path) {
+        for path in self.pending_changes.keys() {
+            if let (Some(target), Some(value)) =
+                (config_value.pointer_mut(path), self.current_value(path))
+            {
                 *target = value.clone();
             }
         }
@@ -379,8 +664,474 @@ impl SettingsState {
     /// Discard all pending changes
     pub fn discard_changes(&mut self) {
         self.pending_changes.clear();
+        self.unsaved_since = None;
         // Rebuild pages from original config
-        self.pages = super::items::build_pages(&self.categories, &self.original_config);
+        self.pages =
+            super::items::build_pages(&self.categories, &self.original_config, self.show_advanced);
+        self.refresh_inherited_flags();
+    }
+
+    /// Show a transient notification, replacing any current one
+    pub fn notify(&mut self, message: impl Into<String>, kind: NotificationKind) {
+        self.notification = Some(SettingsNotification {
+            message: message.into(),
+            kind,
+            posted_at: self.time_source.now(),
+        });
+    }
+
+    /// The notification to display, if any and not yet expired
+    pub fn current_notification(&self) -> Option<&SettingsNotification> {
+        self.notification.as_ref().filter(|n| {
+            n.kind == NotificationKind::Progress
+                || self.time_source.elapsed_since(n.posted_at) < NOTIFICATION_DURATION
+        })
+    }
+
+    /// Drop the notification once it has expired. Returns true if one was
+    /// dropped, so the caller can redraw without waiting for input.
+    pub fn clear_expired_notification(&mut self) -> bool {
+        if self.notification.is_none() || self.current_notification().is_some() {
+            return false;
+        }
+        self.notification = None;
+        // An unanswered reset prompt expires with its notification
+        if self.pending_chord == Some('R') {
+            self.pending_chord = None;
+        }
+        true
+    }
+
+    /// Set the clock and last save time used by the footer (called by Editor when opening settings).
+    pub fn set_time_source(
+        &mut self,
+        time_source: SharedTimeSource,
+        last_saved: Option<std::time::Instant>,
+    ) {
+        self.time_source = time_source;
+        self.last_saved = last_saved;
+    }
+
+    /// Set the directory the path picker starts in (called by Editor when opening settings).
+    pub fn set_working_dir(&mut self, dir: PathBuf) {
+        self.working_dir = dir;
+    }
+
+    /// Open the path picker for the selected file-path setting, in the
+    /// entry dialog if one is open
+    pub fn open_path_picker(&mut self) {
+        let item = match self.entry_dialog() {
+            Some(dialog) => dialog.current_item(),
+            None => self.current_item(),
+        };
+        let Some(SettingControl::Text(text)) = item.map(|item| &item.control) else {
+            return;
+        };
+        self.path_picker = Some(PathPickerState::for_value(&text.value, &self.working_dir));
+    }
+
+    /// Close the path picker, storing `path` in the setting it was opened for
+    pub fn confirm_path_picker(&mut self, path: &Path) {
+        self.path_picker = None;
+        let value = path.to_string_lossy().into_owned();
+        if let Some(dialog) = self.entry_dialog_mut() {
+            if let Some(item) = dialog.current_item_mut() {
+                if let SettingControl::Text(text) = &mut item.control {
+                    text.value = value;
+                    text.cursor = text.value.len();
+                }
+            }
+            return;
+        }
+        if let Some(item) = self.current_item_mut() {
+            if let SettingControl::Text(text) = &mut item.control {
+                text.value = value;
+                text.cursor = text.value.len();
+            }
+        }
+        self.on_value_changed();
+    }
+
+    /// Show or hide advanced settings, keeping pending changes and the
+    /// selected setting when it is still listed
+    pub fn toggle_show_advanced(&mut self) {
+        self.show_advanced = !self.show_advanced;
+        self.rebuild_pages();
+    }
+
+    /// The original config with pending changes applied
+    fn staged_config(&self) -> serde_json::Value {
+        let mut config_value = self.original_config.clone();
+        for path in self.pending_changes.keys() {
+            if let (Some(target), Some(value)) =
+                (config_value.pointer_mut(path), self.current_value(path))
+            {
+                *target = value.clone();
+            }
+        }
+        config_value
+    }
+
+    /// Pages for every setting including hidden advanced ones, for edits
+    /// that apply across all settings
+    fn all_pages(&self) -> Vec<SettingsPage> {
+        super::items::build_pages(&self.categories, &self.staged_config(), true)
+    }
+
+    /// Rebuild pages from the original config plus pending changes, keeping
+    /// the selected setting when it is still listed
+    fn rebuild_pages(&mut self) {
+        let selected_page = self.current_page().map(|page| page.path.clone());
+        let selected_path = self.current_item().map(|item| item.path.clone());
+        self.pages =
+            super::items::build_pages(&self.categories, &self.staged_config(), self.show_advanced);
+
+        // The Starred page comes and goes, so find the selected page by path
+        self.selected_category = self
+            .pages
+            .iter()
+            .position(|page| Some(&page.path) == selected_page.as_ref())
+            .unwrap_or(0);
+        self.selected_item = self
+            .current_page()
+            .and_then(|page| {
+                page.items
+                    .iter()
+                    .position(|item| Some(&item.path) == selected_path.as_ref())
+            })
+            .unwrap_or(0);
+        self.sub_focus = None;
+        self.search_return = None;
+        if self.search_active {
+            self.set_search_query(self.search_query.clone());
+        }
+        self.refresh_inherited_flags();
+        self.ensure_visible();
+    }
+
+    /// Where the focused setting lives, e.g. `["Editor", "Tab Size"]`.
+    /// Starred settings show their home category rather than "Starred".
+    pub fn breadcrumb(&self) -> Vec<String> {
+        let (Some(page), Some(item)) = (self.current_page(), self.current_item()) else {
+            return Vec::new();
+        };
+        let page = if page.is_starred() {
+            self.pages
+                .iter()
+                .filter(|p| !p.is_starred())
+                .find(|p| p.items.iter().any(|i| i.path == item.path))
+                .unwrap_or(page)
+        } else {
+            page
+        };
+
+        let mut crumbs = vec![page.name.clone()];
+        let relative = item.path.strip_prefix(&page.path).unwrap_or(&item.path);
+        let segments: Vec<&str> = relative.split('/').filter(|s| !s.is_empty()).collect();
+        if let Some((_, parents)) = segments.split_last() {
+            crumbs.extend(parents.iter().map(|s| humanize_name(s)));
+        }
+        crumbs.push(item.name.clone());
+        crumbs
+    }
+
+    /// Paths of the starred settings
+    pub fn starred_paths(&self) -> Vec<String> {
+        starred_paths(self.current_value(STARRED_PATH))
+    }
+
+    /// Star the focused setting, or unstar it if already starred.
+    /// Stars are not pending changes: the caller saves them right away, so
+    /// they never prompt about unsaved changes. Returns true if changed.
+    pub fn toggle_star_current(&mut self) -> bool {
+        // Keybinding rows are built from the keymap, not stored settings
+        let Some(path) = self
+            .current_item()
+            .filter(|item| !matches!(item.control, SettingControl::KeyChord(_)))
+            .map(|item| item.path.clone())
+        else {
+            return false;
+        };
+        let mut starred = self.starred_paths();
+        if let Some(idx) = starred.iter().position(|p| *p == path) {
+            starred.remove(idx);
+        } else {
+            starred.push(path);
+        }
+        if let Some(target) = self.original_config.pointer_mut(STARRED_PATH) {
+            *target = serde_json::json!(starred);
+        }
+        self.rebuild_pages();
+        true
+    }
+
+    /// Show the focused setting's documentation link in a notification,
+    /// since the terminal can't open a browser itself
+    pub fn show_current_doc_url(&mut self) {
+        let Some(item) = self.current_item() else {
+            return;
+        };
+        match item.doc_url.clone() {
+            Some(url) => self.notify(format!("Docs: {}", url), NotificationKind::Info),
+            None => {
+                let message = format!("No documentation link for {}", item.name);
+                self.notify(message, NotificationKind::Info);
+            }
+        }
+    }
+
+    /// Wait for the next key press to become the focused action's binding
+    pub fn start_key_capture(&mut self) {
+        if let Some(SettingControl::KeyChord(state)) =
+            self.current_item_mut().map(|item| &mut item.control)
+        {
+            state.capturing = true;
+        }
+    }
+
+    /// Whether the focused keybinding is waiting for its new key
+    pub fn is_capturing_key(&self) -> bool {
+        matches!(
+            self.current_item().map(|item| &item.control),
+            Some(SettingControl::KeyChord(state)) if state.capturing
+        )
+    }
+
+    /// Stop waiting for a new key, keeping the current binding
+    pub fn cancel_key_capture(&mut self) {
+        if let Some(SettingControl::KeyChord(state)) =
+            self.current_item_mut().map(|item| &mut item.control)
+        {
+            state.capturing = false;
+        }
+    }
+
+    /// Stage `key` as the focused action's binding, or drop its custom
+    /// binding when `None`. Warns when another action uses the same key.
+    pub fn rebind_current(&mut self, key: Option<(String, Vec<String>)>) {
+        let Some(SettingControl::KeyChord(state)) = self.current_item().map(|item| &item.control)
+        else {
+            return;
+        };
+        let bindings = keybindings::rebind(
+            self.current_value(KEYBINDINGS_PATH),
+            &state.action,
+            &state.when,
+            &state.default_key,
+            key,
+        );
+        self.set_pending_change(KEYBINDINGS_PATH, bindings);
+        self.rebuild_pages();
+
+        let conflict = match self.current_item().map(|item| &item.control) {
+            Some(SettingControl::KeyChord(state)) => state
+                .conflict
+                .as_ref()
+                .map(|other| format!("{} is also bound to {}", state.key, other)),
+            _ => None,
+        };
+        if let Some(message) = conflict {
+            self.notify(message, NotificationKind::Error);
+        }
+    }
+
+    /// Start typing a setting path to jump to
+    pub fn start_go_to_path(&mut self) {
+        self.cancel_search();
+        self.go_to_path = Some(String::new());
+    }
+
+    /// Leave go-to-path without moving
+    pub fn cancel_go_to_path(&mut self) {
+        self.go_to_path = None;
+    }
+
+    /// Type a character into the go-to-path input
+    pub fn go_to_path_push_char(&mut self, c: char) {
+        if let Some(path) = self.go_to_path.as_mut() {
+            path.push(c);
+        }
+    }
+
+    /// Delete the last character of the go-to-path input
+    pub fn go_to_path_pop_char(&mut self) {
+        if let Some(path) = self.go_to_path.as_mut() {
+            path.pop();
+        }
+    }
+
+    /// Dotted paths of the listed settings starting with `prefix`, with the
+    /// page and item index of each, in path order
+    fn settings_with_path_prefix(&self, prefix: &str) -> Vec<(String, (usize, usize))> {
+        // Accept JSON pointer style too, e.g. `/editor/tab_size`
+        let prefix = prefix.trim_start_matches('/').replace('/', ".");
+        let mut settings: Vec<(String, (usize, usize))> = self
+            .pages
+            .iter()
+            .enumerate()
+            .filter(|(_, page)| !page.is_starred())
+            .flat_map(|(page_index, page)| {
+                page.items
+                    .iter()
+                    .enumerate()
+                    .map(move |(item_index, item)| {
+                        let dotted = item.path.trim_start_matches('/').replace('/', ".");
+                        (dotted, (page_index, item_index))
+                    })
+            })
+            .filter(|(path, _)| path.starts_with(&prefix))
+            .collect();
+        settings.sort();
+        settings
+    }
+
+    /// Dotted paths of the listed settings that start with the typed path,
+    /// e.g. `editor.tab_size` for `editor.t`
+    pub fn go_to_path_completions(&self) -> Vec<String> {
+        let typed = self.go_to_path.as_deref().unwrap_or_default();
+        self.settings_with_path_prefix(typed)
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect()
+    }
+
+    /// Extend the typed path to the longest prefix all completions share
+    pub fn complete_go_to_path(&mut self) {
+        let completions = self.go_to_path_completions();
+        let Some((first, rest)) = completions.split_first() else {
+            return;
+        };
+        let common = rest.iter().fold(first.as_str(), |common, path| {
+            let len = common
+                .char_indices()
+                .zip(path.chars())
+                .take_while(|((_, a), b)| a == b)
+                .last()
+                .map_or(0, |((i, a), _)| i + a.len_utf8());
+            &common[..len]
+        });
+        self.go_to_path = Some(common.to_string());
+    }
+
+    /// Focus the setting at the typed path, or the first completion when
+    /// there is no exact match. Returns false if nothing matches.
+    pub fn apply_go_to_path(&mut self) -> bool {
+        let Some(typed) = self.go_to_path.take() else {
+            return false;
+        };
+        // Sorted by path, so an exact match comes before longer completions
+        match self.settings_with_path_prefix(&typed).into_iter().next() {
+            Some((_, (page_index, item_index))) => {
+                self.focus_item(page_index, item_index);
+                true
+            }
+            None => {
+                self.notify(format!("No setting at {}", typed), NotificationKind::Error);
+                false
+            }
+        }
+    }
+
+    /// Start find/replace over setting values
+    pub fn start_value_replace(&mut self) {
+        self.cancel_search();
+        self.value_replace = Some(ValueReplaceState::default());
+    }
+
+    /// Leave find/replace without changing anything
+    pub fn cancel_value_replace(&mut self) {
+        self.value_replace = None;
+    }
+
+    /// Type a character into the active find/replace field
+    pub fn value_replace_push_char(&mut self, c: char) {
+        if let Some(replace) = self.value_replace.as_mut() {
+            if replace.editing_replace {
+                replace.replace.push(c);
+            } else {
+                replace.find.push(c);
+                self.count_value_replace_matches();
+            }
+        }
+    }
+
+    /// Delete the last character of the active find/replace field
+    pub fn value_replace_pop_char(&mut self) {
+        if let Some(replace) = self.value_replace.as_mut() {
+            if replace.editing_replace {
+                replace.replace.pop();
+            } else {
+                replace.find.pop();
+                self.count_value_replace_matches();
+            }
+        }
+    }
+
+    /// Count the settings the find text matches, hidden advanced ones included
+    fn count_value_replace_matches(&mut self) {
+        let pages = self.all_pages();
+        if let Some(replace) = self.value_replace.as_mut() {
+            replace.matches = count_value_matches(&pages, &replace.find);
+        }
+    }
+
+    /// Switch typing between the find and replace fields
+    pub fn value_replace_switch_field(&mut self) {
+        if let Some(replace) = self.value_replace.as_mut() {
+            replace.editing_replace = !replace.editing_replace;
+        }
+    }
+
+    /// Replace the find text in every matching setting value, including
+    /// hidden advanced settings, and stage the results as pending changes.
+    /// Returns the number of settings changed.
+    pub fn apply_value_replace(&mut self) -> usize {
+        let Some(replace) = self.value_replace.take() else {
+            return 0;
+        };
+        if replace.find.is_empty() {
+            return 0;
+        }
+
+        let mut changed = 0;
+        let mut staged = Vec::new();
+        for item in self
+            .all_pages()
+            .iter()
+            .filter(|page| !page.is_starred())
+            .flat_map(|page| &page.items)
+        {
+            if !is_value_replaceable(item) {
+                continue;
+            }
+            let value = control_to_value(&item.control);
+            if !value_contains(&value, &replace.find) {
+                continue;
+            }
+            changed += 1;
+            let new_value = replace_in_strings(&value, &replace.find, &replace.replace);
+            match (&item.control, &value, new_value) {
+                // Stage map entries one by one, as the entry dialog does
+                (
+                    SettingControl::Map(_),
+                    serde_json::Value::Object(old),
+                    serde_json::Value::Object(new),
+                ) => {
+                    for (key, entry) in new {
+                        if old.get(&key) != Some(&entry) {
+                            staged.push((format!("{}/{}", item.path, key), entry));
+                        }
+                    }
+                }
+                (_, _, new_value) => staged.push((item.path.clone(), new_value)),
+            }
+        }
+
+        for (path, value) in staged {
+            self.set_pending_change(&path, value);
+        }
+        self.rebuild_pages();
+        changed
     }
 
     /// Set the target layer for saving changes.
@@ -390,6 +1141,8 @@ impl SettingsState {
             self.target_layer = layer;
             // Clear pending changes when switching layers
             self.pending_changes.clear();
+            self.unsaved_since = None;
+            self.rebuild_pages();
         }
     }
 
@@ -403,6 +1156,8 @@ impl SettingsState {
         };
         // Clear pending changes when switching layers
         self.pending_changes.clear();
+        self.unsaved_since = None;
+        self.rebuild_pages();
     }
 
     /// Get a display name for the current target layer.
@@ -418,6 +1173,150 @@ impl SettingsState {
     /// Set the layer sources map (called by Editor when opening settings).
     pub fn set_layer_sources(&mut self, sources: HashMap<String, ConfigLayer>) {
         self.layer_sources = sources;
+        self.refresh_inherited_flags();
+    }
+
+    /// Set the config each layer inherits (called by Editor when opening settings).
+    pub fn set_inherited_configs(&mut self, configs: HashMap<ConfigLayer, serde_json::Value>) {
+        self.inherited_configs = configs;
+        self.refresh_inherited_flags();
+    }
+
+    /// Value the target layer inherits for a setting, if known
+    pub fn inherited_value(&self, path: &str) -> Option<&serde_json::Value> {
+        self.inherited_configs
+            .get(&self.target_layer)?
+            .pointer(path)
+    }
+
+    /// Set what each layer's config file sets (called by Editor when opening settings).
+    pub fn set_layer_configs(&mut self, configs: HashMap<ConfigLayer, serde_json::Value>) {
+        self.layer_configs = configs;
+        self.refresh_inherited_flags();
+    }
+
+    /// The target layer's saved value for a setting, if its file sets one
+    pub fn layer_value(&self, path: &str) -> Option<&serde_json::Value> {
+        self.layer_configs
+            .get(&self.target_layer)?
+            .pointer(path)
+            .filter(|value| !value.is_null())
+    }
+
+    /// The value a setting had of its own in the target layer when the
+    /// dialog was opened, or `None` if it inherited. A value set above the
+    /// target layer or forced at runtime counts as its own.
+    fn saved_explicit_value(&self, path: &str) -> Option<&serde_json::Value> {
+        self.layer_value(path).or_else(|| {
+            self.original_value(path)
+                .filter(|value| Some(*value) != self.inherited_value(path))
+        })
+    }
+
+    /// The target layer's own value for a setting including any pending
+    /// change, or `None` if the setting inherits
+    fn explicit_value(&self, path: &str) -> Option<&serde_json::Value> {
+        match self.pending_changes.get(path) {
+            Some(value) => Some(value).filter(|value| !value.is_null()),
+            None => self.saved_explicit_value(path),
+        }
+    }
+
+    /// Whether a setting has no value of its own in the target layer and
+    /// takes the one from the layers below
+    pub fn is_inherited(&self, path: &str) -> bool {
+        self.inherited_value(path).is_some() && self.explicit_value(path).is_none()
+    }
+
+    /// Boolean settings the target layer sets to the value it would inherit
+    /// anyway. Layer files only keep their differences from the layers
+    /// below, so saving writes these back on top to keep them explicit.
+    pub fn explicit_inherited_values(&self) -> Vec<(String, serde_json::Value)> {
+        self.all_settings()
+            .into_iter()
+            .filter_map(|setting| {
+                let value = self.explicit_value(&setting.path)?;
+                (value.is_boolean() && self.inherited_value(&setting.path) == Some(value))
+                    .then(|| (setting.path.clone(), value.clone()))
+            })
+            .collect()
+    }
+
+    /// Show each toggle's own value in the target layer, or the inherited
+    /// one with a marker when it has none
+    fn refresh_inherited_flags(&mut self) {
+        let states: Vec<(usize, usize, bool, Option<bool>)> = self
+            .pages
+            .iter()
+            .enumerate()
+            .flat_map(|(page_idx, page)| {
+                page.items
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, item)| matches!(item.control, SettingControl::Toggle(_)))
+                    .map(move |(item_idx, item)| (page_idx, item_idx, &item.path))
+            })
+            .filter_map(|(page_idx, item_idx, path)| {
+                let inherited = self.inherited_value(path)?.as_bool()?;
+                let explicit = self.explicit_value(path).and_then(|value| value.as_bool());
+                Some((page_idx, item_idx, inherited, explicit))
+            })
+            .collect();
+        for (page_idx, item_idx, inherited, explicit) in states {
+            if let SettingControl::Toggle(state) = &mut self.pages[page_idx].items[item_idx].control
+            {
+                state.inherited_value = inherited;
+                state.value = explicit;
+            }
+        }
+    }
+
+    /// Advance the focused toggle. Where the target layer can inherit the
+    /// setting it steps through on, off and inherit; inheriting drops the
+    /// layer's own value when saved.
+    pub fn toggle_current(&mut self) {
+        let layered = self
+            .current_item()
+            .is_some_and(|item| self.inherited_value(&item.path).is_some());
+        let change = self.current_item_mut().and_then(|item| {
+            let SettingControl::Toggle(toggle) = &mut item.control else {
+                return None;
+            };
+            if layered {
+                toggle.cycle();
+            } else {
+                toggle.toggle();
+            }
+            let effective = serde_json::Value::Bool(toggle.checked());
+            item.modified = item.default.as_ref() != Some(&effective);
+            Some((item.path.clone(), toggle.value))
+        });
+        let Some((path, value)) = change else {
+            return;
+        };
+
+        let value = value.map(serde_json::Value::Bool);
+        let staged = value.clone().unwrap_or(serde_json::Value::Null);
+        if layered {
+            // An explicit value is a change even when it equals the inherited one
+            let unchanged = self.saved_explicit_value(&path) == value.as_ref();
+            self.record_pending_change(&path, staged, unchanged);
+        } else {
+            self.set_pending_change(&path, staged);
+        }
+        self.refresh_inherited_flags();
+    }
+
+    /// Set the config file path of each layer (called by Editor when opening settings).
+    pub fn set_config_paths(&mut self, paths: HashMap<ConfigLayer, PathBuf>) {
+        self.config_paths = paths;
+    }
+
+    /// Get the config file that the current target layer is saved to.
+    pub fn target_config_path(&self) -> Option<&Path> {
+        self.config_paths
+            .get(&self.target_layer)
+            .map(PathBuf::as_path)
     }
 
     /// Get the source layer for a setting path.
@@ -441,6 +1340,15 @@ impl SettingsState {
 
     /// Reset the current item to its default value
     pub fn reset_current_to_default(&mut self) {
+        // A keybinding goes back to the keymap's key by dropping its custom one
+        if matches!(
+            self.current_item().map(|item| &item.control),
+            Some(SettingControl::KeyChord(_))
+        ) {
+            self.rebind_current(None);
+            return;
+        }
+
         // Get the info we need first, then release the borrow
         let reset_info = self.current_item().and_then(|item| {
             item.default
@@ -449,14 +1357,75 @@ impl SettingsState {
         });
 
         if let Some((path, default)) = reset_info {
-            self.set_pending_change(&path, default.clone());
+            self.set_pending_change(&path, self.default_change(&path, &default));
 
             // Now update the control state
             if let Some(item) = self.current_item_mut() {
                 update_control_from_value(&mut item.control, &default);
                 item.modified = false;
             }
+            self.refresh_inherited_flags();
+        }
+    }
+
+    /// The change that resets a setting to its default. A toggle inherits
+    /// instead when the layers below give it the default anyway.
+    fn default_change(&self, path: &str, default: &serde_json::Value) -> serde_json::Value {
+        if default.is_boolean() && self.inherited_value(path) == Some(default) {
+            serde_json::Value::Null
+        } else {
+            default.clone()
+        }
+    }
+
+    /// Every setting in the schema, including hidden advanced ones
+    fn all_settings(&self) -> Vec<&SettingSchema> {
+        fn collect<'a>(categories: &'a [SettingCategory], out: &mut Vec<&'a SettingSchema>) {
+            for category in categories {
+                out.extend(&category.settings);
+                collect(&category.subcategories, out);
+            }
+        }
+        let mut settings = Vec::new();
+        collect(&self.categories, &mut settings);
+        settings
+    }
+
+    /// Stage the built-in default for every setting that a config file or
+    /// an unsaved edit sets to something else, including hidden advanced
+    /// settings. A setting a layer above the target sets keeps that value,
+    /// so it is left alone. Returns how many settings were reset and how
+    /// many were left overridden.
+    pub fn reset_all_to_defaults(&mut self) -> (usize, usize) {
+        let customized: Vec<(String, serde_json::Value)> = self
+            .all_settings()
+            .into_iter()
+            .filter(|s| {
+                self.layer_sources.contains_key(&s.path)
+                    || self.pending_changes.contains_key(&s.path)
+            })
+            .filter_map(|s| {
+                let default = s.default.as_ref()?;
+                (self.current_value(&s.path) != Some(default))
+                    .then(|| (s.path.clone(), default.clone()))
+            })
+            .collect();
+        let (overridden, resets): (Vec<_>, Vec<_>) =
+            customized.into_iter().partition(|(path, _)| {
+                !self.pending_changes.contains_key(path)
+                    && self
+                        .layer_sources
+                        .get(path)
+                        .is_some_and(|layer| layer.precedence() > self.target_layer.precedence())
+            });
+
+        let count = resets.len();
+        for (path, default) in resets {
+            let change = self.default_change(&path, &default);
+            self.set_pending_change(&path, change);
         }
+        self.rebuild_pages();
+        (count, overridden.len())
     }
 
     /// Handle a value change from user interaction
@@ -503,6 +1472,7 @@ impl SettingsState {
                     SettingControl::Map(state) => state.focus = focus,
                     SettingControl::ObjectArray(state) => state.focus = focus,
                     SettingControl::Json(state) => state.focus = focus,
+                    SettingControl::KeyChord(state) => state.focus = focus,
                     SettingControl::Complex { .. } => {}
                 }
             }
@@ -511,6 +1481,7 @@ impl SettingsState {
 
     /// Start search mode
     pub fn start_search(&mut self) {
+        self.search_return = None;
         self.search_active = true;
         self.search_query.clear();
         self.search_results.clear();
@@ -528,21 +1499,33 @@ impl SettingsState {
     /// Update search query and refresh results
     pub fn set_search_query(&mut self, query: String) {
         self.search_query = query;
-        self.search_results = search_settings(&self.pages, &self.search_query);
-        self.selected_search_result = 0;
+        self.refresh_search_results();
     }
 
     /// Add a character to the search query
     pub fn search_push_char(&mut self, c: char) {
         self.search_query.push(c);
-        self.search_results = search_settings(&self.pages, &self.search_query);
-        self.selected_search_result = 0;
+        self.refresh_search_results();
     }
 
     /// Remove the last character from the search query
     pub fn search_pop_char(&mut self) {
         self.search_query.pop();
-        self.search_results = search_settings(&self.pages, &self.search_query);
+        self.refresh_search_results();
+    }
+
+    /// Switch between searching names only and names plus current values
+    pub fn toggle_search_values(&mut self) {
+        self.search_values = !self.search_values;
+        self.refresh_search_results();
+    }
+
+    fn refresh_search_results(&mut self) {
+        self.search_results = if self.search_values {
+            search_settings_and_values(&self.pages, &self.search_query)
+        } else {
+            search_settings(&self.pages, &self.search_query)
+        };
         self.selected_search_result = 0;
     }
 
@@ -562,25 +1545,46 @@ impl SettingsState {
         }
     }
 
+    /// Select and focus an item, scrolling it into view
+    fn focus_item(&mut self, page_index: usize, item_index: usize) {
+        self.selected_category = page_index;
+        self.selected_item = item_index;
+        self.focus_panel = FocusPanel::Settings;
+        // Reset scroll offset but preserve viewport for ensure_visible
+        self.scroll_panel.scroll.offset = 0;
+        // Update content height for the new category's items
+        if let Some(page) = self.pages.get(self.selected_category) {
+            let rows = RowItem::build(&page.items, self.two_column);
+            self.scroll_panel.update_content_height(&rows);
+        }
+        self.sub_focus = None;
+        self.init_map_focus(true);
+        self.ensure_visible();
+    }
+
     /// Jump to the currently selected search result
     pub fn jump_to_search_result(&mut self) {
         if let Some(result) = self.search_results.get(self.selected_search_result) {
-            self.selected_category = result.page_index;
-            self.selected_item = result.item_index;
-            self.focus_panel = FocusPanel::Settings;
-            // Reset scroll offset but preserve viewport for ensure_visible
-            self.scroll_panel.scroll.offset = 0;
-            // Update content height for the new category's items
-            if let Some(page) = self.pages.get(self.selected_category) {
-                self.scroll_panel.update_content_height(&page.items);
-            }
-            self.sub_focus = None;
-            self.init_map_focus(true);
-            self.ensure_visible();
+            let (page_index, item_index) = (result.page_index, result.item_index);
+            self.focus_item(page_index, item_index);
+            let origin = (self.search_query.clone(), self.selected_search_result);
             self.cancel_search();
+            self.search_return = Some(origin);
         }
     }
 
+    /// Reopen the search the current item was jumped to from, with the same
+    /// query and selected result. Returns false if there is none.
+    pub fn return_to_search_results(&mut self) -> bool {
+        let Some((query, selected)) = self.search_return.take() else {
+            return false;
+        };
+        self.search_active = true;
+        self.set_search_query(query);
+        self.selected_search_result = selected.min(self.search_results.len().saturating_sub(1));
+        true
+    }
+
     /// Get the currently selected search result
     pub fn current_search_result(&self) -> Option<&SearchResult> {
         self.search_results.get(self.selected_search_result)
@@ -589,7 +1593,7 @@ impl SettingsState {
     /// Show the unsaved changes confirmation dialog
     pub fn show_confirm_dialog(&mut self) {
         self.showing_confirm_dialog = true;
-        self.confirm_dialog_selection = 0; // Default to "Save and Exit"
+        self.confirm_dialog_selection = self.last_confirm_choice;
     }
 
     /// Hide the confirmation dialog
@@ -1177,6 +2181,17 @@ impl SettingsState {
         }
     }
 
+    /// Move the focused TextList item up or down by one place
+    pub fn text_move_focused_item(&mut self, up: bool) {
+        if let Some(item) = self.current_item_mut() {
+            if let SettingControl::TextList(state) = &mut item.control {
+                state.move_focused_item(up);
+            }
+        }
+        // Record the change
+        self.on_value_changed();
+    }
+
     /// Add new item in TextList/Map (from the new item field)
     pub fn text_add_item(&mut self) {
         if let Some(item) = self.current_item_mut() {
@@ -1239,10 +2254,12 @@ impl SettingsState {
             // Update content height since item is now taller
             let selected_item = self.selected_item;
             if let Some(page) = self.pages.get(self.selected_category) {
-                self.scroll_panel.update_content_height(&page.items);
+                let rows = RowItem::build(&page.items, self.two_column);
+                self.scroll_panel.update_content_height(&rows);
                 // Ensure the dropdown item is visible with its new expanded height
-                self.scroll_panel
-                    .ensure_focused_visible(&page.items, selected_item, None);
+                if let Some(row) = row_of(&rows, selected_item) {
+                    self.scroll_panel.ensure_focused_visible(&rows, row, None);
+                }
             }
         }
     }
@@ -1522,21 +2539,60 @@ impl SettingsState {
         }
     }
 
-    /// Get list of pending changes for display
-    pub fn get_change_descriptions(&self) -> Vec<String> {
-        self.pending_changes
+    /// Get list of pending changes for display, sorted by path
+    pub fn get_change_descriptions(&self) -> Vec<ChangeDescription> {
+        let mut changes: Vec<ChangeDescription> = self
+            .pending_changes
             .iter()
-            .map(|(path, value)| {
-                let value_str = match value {
-                    serde_json::Value::Bool(b) => b.to_string(),
-                    serde_json::Value::Number(n) => n.to_string(),
-                    serde_json::Value::String(s) => format!("\"{}\"", s),
-                    _ => value.to_string(),
-                };
-                format!("{}: {}", path, value_str)
+            .map(|(path, value)| ChangeDescription {
+                path: path.clone(),
+                old: self.original_value(path).map(format_value),
+                new: match (value, self.current_value(path)) {
+                    (serde_json::Value::Null, Some(inherited)) => {
+                        format!("{} (inherited)", format_value(inherited))
+                    }
+                    _ => format_value(value),
+                },
+            })
+            .collect();
+        changes.sort_by(|a, b| a.path.cmp(&b.path));
+        changes
+    }
+
+    /// Settings whose saved value differs from the default, in page order.
+    /// `old` holds the default and `new` the saved value. Only settings set
+    /// in a config file count, so values the editor forces at runtime are
+    /// left out.
+    pub fn customized_settings(&self) -> Vec<ChangeDescription> {
+        let mut seen = std::collections::HashSet::new();
+        self.pages
+            .iter()
+            .filter(|page| !page.is_starred())
+            .flat_map(|page| &page.items)
+            .filter(|item| item.modified && self.layer_sources.contains_key(&item.path))
+            .filter(|item| seen.insert(item.path.as_str()))
+            .map(|item| ChangeDescription {
+                path: item.path.clone(),
+                old: item.default.as_ref().map(format_value),
+                new: format_value(&control_to_value(&item.control)),
             })
             .collect()
     }
+
+    /// Show the "what changed" panel if any setting is customized
+    pub fn show_customizations(&mut self) {
+        self.showing_customizations = !self.customized_settings().is_empty();
+    }
+}
+
+/// Format a setting value for display in change lists
+fn format_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Bool(b) => b.to_string(),
+        serde_json::Value::Number(n) => n.to_string(),
+        serde_json::Value::String(s) => format!("\"{}\"", s),
+        _ => value.to_string(),
+    }
 }
 
 /// Update a control's state from a JSON value
@@ -1544,7 +2600,7 @@ fn update_control_from_value(control: &mut SettingControl, value: &serde_json::V
     match control {
         SettingControl::Toggle(state) => {
             if let Some(b) = value.as_bool() {
-                state.checked = b;
+                state.value = Some(b);
             }
         }
         SettingControl::Number(state) => {
@@ -1597,6 +2653,11 @@ fn update_control_from_value(control: &mut SettingControl, value: &serde_json::V
             state.editor.set_value(&json_str);
             state.scroll_offset = 0;
         }
+        SettingControl::KeyChord(state) => {
+            if let Some(s) = value.as_str() {
+                state.key = s.to_string();
+            }
+        }
         SettingControl::Complex { .. } => {}
     }
 }
@@ -1685,6 +2746,39 @@ mod tests {
         assert!(!state.visible);
     }
 
+    #[test]
+    fn test_value_replace_includes_hidden_advanced_settings() {
+        const SCHEMA: &str = r#"
+{
+  "type": "object",
+  "properties": {
+    "theme": {
+      "type": "string",
+      "default": "dark",
+      "x-advanced": true
+    }
+  },
+  "$defs": {}
+}
+"#;
+        let mut config = test_config();
+        config.theme = serde_json::from_value(serde_json::json!("old-theme")).unwrap();
+        let mut state = SettingsState::new(SCHEMA, &config).unwrap();
+        assert!(!state.show_advanced);
+
+        state.start_value_replace();
+        "old".chars().for_each(|c| state.value_replace_push_char(c));
+        assert_eq!(state.value_replace.as_ref().unwrap().matches, 1);
+        state.value_replace_switch_field();
+        "new".chars().for_each(|c| state.value_replace_push_char(c));
+
+        assert_eq!(state.apply_value_replace(), 1);
+        assert_eq!(
+            state.current_value("/theme"),
+            Some(&serde_json::json!("new-theme"))
+        );
+    }
+
     // Schema with dropdown (enum) and number controls for testing
     const TEST_SCHEMA_CONTROLS: &str = r#"
 {
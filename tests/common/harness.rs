@@ -958,6 +958,32 @@ impl EditorTestHarness {
         buffer.content.get(pos).map(|cell| cell.style())
     }
 
+    /// Find the screen position (x, y) of the first occurrence of `text`.
+    /// Positions are in cells, so they can be passed to `get_cell_style`.
+    pub fn find_text_on_screen(&self, text: &str) -> Option<(u16, u16)> {
+        let buffer = self.buffer();
+        for y in 0..buffer.area.height {
+            let mut row = String::new();
+            let mut cell_starts = Vec::new();
+            for x in 0..buffer.area.width {
+                cell_starts.push((row.len(), x));
+                if let Some(cell) = buffer.content.get(buffer.index_of(x, y)) {
+                    row.push_str(cell.symbol());
+                }
+            }
+            if let Some(byte_idx) = row.find(text) {
+                let x = cell_starts
+                    .iter()
+                    .rev()
+                    .find(|(start, _)| *start <= byte_idx)
+                    .map(|(_, x)| *x)
+                    .unwrap_or(0);
+                return Some((x, y));
+            }
+        }
+        None
+    }
+
     /// Check if a cell at the given position is a scrollbar thumb.
     ///
     /// Since the scrollbar is rendered using background colors (not characters),
@@ -993,6 +1019,28 @@ impl EditorTestHarness {
         false
     }
 
+    /// Get the position of the settings list scrollbar thumb as a ratio,
+    /// 0.0 with the thumb at the top of the track and 1.0 at the bottom.
+    ///
+    /// The thumb is located from the rendered cell colors, so this reflects
+    /// what is on screen rather than the scroll state.
+    /// Returns None if settings is closed or the list doesn't scroll.
+    pub fn scrollbar_ratio(&self) -> Option<f32> {
+        let area = self.editor.settings_layout()?.scrollbar_area?;
+        // Track and thumb colors overlap across themes, so match the exact
+        // thumb color of the current theme
+        let thumb = fresh::view::ui::ScrollbarColors::from_theme(self.editor.theme()).thumb;
+        let thumb_rows: Vec<u16> = (area.top()..area.bottom())
+            .filter(|&y| self.get_cell_style(area.x, y).and_then(|s| s.bg) == Some(thumb))
+            .collect();
+        let thumb_start = *thumb_rows.first()?;
+        let free_rows = area.height.saturating_sub(thumb_rows.len() as u16);
+        if free_rows == 0 {
+            return Some(0.0);
+        }
+        Some((thumb_start - area.y) as f32 / free_rows as f32)
+    }
+
     /// Get the text content of a specific screen row
     pub fn get_row_text(&self, y: u16) -> String {
         let buffer = self.buffer();
@@ -1048,6 +1096,86 @@ impl EditorTestHarness {
         );
     }
 
+    /// Get the screen area of the topmost rendered layer.
+    ///
+    /// This is the dialog drawn over the settings modal (help, confirm or entry
+    /// dialog) if one is showing, the settings modal if it is open, and the
+    /// whole screen otherwise.
+    pub fn topmost_layer_area(&self) -> ratatui::layout::Rect {
+        match self.editor.settings_layout() {
+            Some(layout) => layout.overlay_area.unwrap_or(layout.modal_area),
+            None => self.buffer().area,
+        }
+    }
+
+    /// Get the text of the topmost layer, one line per row
+    pub fn topmost_layer_text(&self) -> String {
+        let area = self.topmost_layer_area();
+        let buffer = self.buffer();
+        let mut lines = Vec::new();
+        for y in area.top()..area.bottom() {
+            let mut line = String::new();
+            for x in area.left()..area.right() {
+                if let Some(cell) = buffer.content.get(buffer.index_of(x, y)) {
+                    line.push_str(cell.symbol());
+                }
+            }
+            lines.push(line);
+        }
+        lines.join("\n")
+    }
+
+    /// Verify text is visible in the topmost layer.
+    /// Unlike `assert_screen_contains`, this fails if the text is only visible
+    /// in a layer that has another dialog drawn on top of it.
+    pub fn assert_topmost_contains(&self, text: &str) {
+        let topmost = self.topmost_layer_text();
+        assert!(
+            topmost.contains(text),
+            "Expected topmost layer to contain '{text}'\nTopmost layer:\n{topmost}\nScreen content:\n{}",
+            self.screen_to_string()
+        );
+    }
+
+    /// Verify text is not visible in the topmost layer
+    pub fn assert_topmost_not_contains(&self, text: &str) {
+        let topmost = self.topmost_layer_text();
+        assert!(
+            !topmost.contains(text),
+            "Expected topmost layer to not contain '{text}'\nTopmost layer:\n{topmost}"
+        );
+    }
+
+    /// Get the value of a setting in the open settings dialog, including
+    /// pending changes. `path` is a JSON pointer such as "/check_for_updates".
+    /// Returns None if settings is closed or the path doesn't exist.
+    pub fn get_setting(&self, path: &str) -> Option<serde_json::Value> {
+        self.editor
+            .settings_state()
+            .and_then(|state| state.current_value(path).cloned())
+    }
+
+    /// Get the unsaved changes in the open settings dialog as
+    /// (path, old value, new value), sorted by path
+    pub fn pending_changes(&self) -> Vec<(String, serde_json::Value, serde_json::Value)> {
+        let Some(state) = self.editor.settings_state() else {
+            return Vec::new();
+        };
+        let mut changes: Vec<_> = state
+            .pending_changes
+            .iter()
+            .map(|(path, new)| {
+                let old = state
+                    .original_value(path)
+                    .cloned()
+                    .unwrap_or(serde_json::Value::Null);
+                (path.clone(), old, new.clone())
+            })
+            .collect();
+        changes.sort_by(|a, b| a.0.cmp(&b.0));
+        changes
+    }
+
     /// Get the buffer content (not screen, actual buffer text)
     /// Returns None for large files with unloaded regions (lazy loading)
     pub fn get_buffer_content(&self) -> Option<String> {
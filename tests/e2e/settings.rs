@@ -2,6 +2,8 @@
 
 use crate::common::harness::EditorTestHarness;
 use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::view::settings::FocusPanel;
+use ratatui::style::Modifier;
 
 /// Test opening settings modal with Ctrl+,
 #[test]
@@ -148,6 +150,50 @@ fn test_settings_help_overlay() {
     harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
 }
 
+/// Test that the help overlay is composited above the settings modal
+#[test]
+fn test_settings_help_overlay_is_topmost() {
+    let mut harness = EditorTestHarness::new(100, 40).unwrap();
+
+    // Open settings - the modal is the topmost layer
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_topmost_contains("General");
+    harness.assert_topmost_not_contains("Keyboard Shortcuts");
+
+    // Open help over settings
+    harness
+        .send_key(KeyCode::Char('?'), KeyModifiers::NONE)
+        .unwrap();
+    let help_area = harness.topmost_layer_area();
+    let help_text = harness.topmost_layer_text();
+
+    // Help content wins on the cells it covers
+    harness.assert_topmost_contains("Keyboard Shortcuts");
+    harness.assert_topmost_contains("Save settings");
+
+    // The category list is still on screen beside the overlay, but not on top
+    harness.assert_screen_contains("General");
+    harness.assert_topmost_not_contains("General");
+
+    // Closing help uncovers different settings content in the same cells
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    let modal_area = harness.topmost_layer_area();
+    assert!(modal_area.width > help_area.width);
+    let covered: Vec<String> = (help_area.top()..help_area.bottom())
+        .map(|y| {
+            (help_area.left()..help_area.right())
+                .filter_map(|x| harness.get_cell(x, y))
+                .collect()
+        })
+        .collect();
+    assert_ne!(covered.join("\n"), help_text);
+    assert!(!covered.join("\n").contains("Keyboard Shortcuts"));
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+}
+
 /// Test search text input is displayed in search box
 #[test]
 fn test_settings_search_text_displays() {
@@ -191,6 +237,47 @@ fn test_settings_search_text_displays() {
     harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
 }
 
+/// Test that fuzzy-matched characters are highlighted in search results
+#[test]
+fn test_settings_search_highlights_matched_characters() {
+    let mut harness = EditorTestHarness::new(100, 40).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("chk").unwrap();
+
+    // The path is shown in the breadcrumb line of the result
+    let (x, y) = harness
+        .find_text_on_screen("check_for_updates")
+        .expect("check_for_updates should be in the search results");
+    let style_at = |offset: u16| harness.get_cell_style(x + offset, y).unwrap();
+
+    // "chk" matches 'c' (0), 'h' (1) and 'k' (4) in "check_for_updates"
+    let plain = style_at(2);
+    for offset in [0, 1, 4] {
+        let matched = style_at(offset);
+        assert_ne!(
+            matched, plain,
+            "matched character at offset {offset} should be highlighted"
+        );
+        assert!(matched.add_modifier.contains(Modifier::BOLD));
+    }
+    for offset in [2, 3, 5] {
+        assert_eq!(
+            style_at(offset),
+            plain,
+            "unmatched character at offset {offset} should not be highlighted"
+        );
+    }
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+}
+
 /// Test toggling a setting shows modified indicator
 #[test]
 fn test_settings_toggle_shows_modified() {
@@ -240,6 +327,153 @@ fn test_settings_toggle_shows_modified() {
         .unwrap();
 }
 
+/// Test that Escape from a setting jumped to via search returns to the
+/// search results with the same query
+#[test]
+fn test_settings_escape_returns_to_search_results() {
+    let mut harness = EditorTestHarness::new(100, 40).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("line").unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    let selected = harness
+        .editor()
+        .settings_state()
+        .unwrap()
+        .selected_search_result;
+
+    // Jump to the result: search closes
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert!(!harness.editor().settings_state().unwrap().search_active);
+
+    // Escape brings back the results list with the same query and selection
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert!(harness.editor().is_settings_open());
+    let state = harness.editor().settings_state().unwrap();
+    assert!(state.search_active);
+    assert_eq!(state.search_query, "line");
+    assert_eq!(state.selected_search_result, selected);
+    assert!(!state.search_results.is_empty());
+    harness.assert_screen_contains("line");
+
+    // Escape again leaves search, and the next one closes settings
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    assert!(!harness.editor().settings_state().unwrap().search_active);
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    assert!(!harness.editor().is_settings_open());
+}
+
+/// Test that toggling a setting is visible through the harness without
+/// reading rendered text
+#[test]
+fn test_settings_pending_changes_accessor() {
+    let mut harness = EditorTestHarness::new(100, 40).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    let original = harness
+        .get_setting("/check_for_updates")
+        .expect("check_for_updates should exist");
+    assert!(harness.pending_changes().is_empty());
+
+    // Jump to the setting and toggle it
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("check").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    let toggled = serde_json::Value::Bool(!original.as_bool().unwrap());
+    assert_eq!(
+        harness.pending_changes(),
+        vec![(
+            "/check_for_updates".to_string(),
+            original.clone(),
+            toggled.clone()
+        )]
+    );
+    assert_eq!(harness.get_setting("/check_for_updates"), Some(toggled));
+
+    // Stepping on through inherit back to the original value clears the
+    // pending change
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    assert_eq!(harness.pending_changes().len(), 1);
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    assert!(harness.pending_changes().is_empty());
+    assert_eq!(harness.get_setting("/check_for_updates"), Some(original));
+
+    // Back to the results, out of search, then close
+    for _ in 0..3 {
+        harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    }
+    assert!(harness.get_setting("/check_for_updates").is_none());
+}
+
+/// Test that saving a change records it in the settings audit log
+#[test]
+fn test_settings_save_records_audit_entry() {
+    let mut harness = EditorTestHarness::new(100, 40).unwrap();
+    assert!(harness.editor().settings_audit_log().is_empty());
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    let original = harness.get_setting("/check_for_updates").unwrap();
+
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("check").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    // Nothing is logged until the change is saved
+    assert!(harness.editor().settings_audit_log().is_empty());
+
+    // The timestamp follows the editor's clock, not the system's
+    harness.advance_time(std::time::Duration::from_secs(10 * 24 * 60 * 60));
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+
+    let log = harness.editor().settings_audit_log();
+    assert_eq!(log.len(), 1);
+    assert_eq!(log[0].path, "/check_for_updates");
+    assert_eq!(log[0].old, original);
+    assert_eq!(
+        log[0].new,
+        serde_json::Value::Bool(!original.as_bool().unwrap())
+    );
+    let saved_at = chrono::DateTime::parse_from_rfc3339(&log[0].timestamp).unwrap();
+    assert!(saved_at > chrono::Local::now() + chrono::Duration::days(9));
+}
+
 /// Test confirmation dialog shows pending changes
 #[test]
 fn test_confirmation_dialog_shows_changes() {
@@ -271,8 +505,11 @@ fn test_confirmation_dialog_shows_changes() {
         .unwrap();
     harness.render().unwrap();
 
-    // Press Escape to trigger confirmation dialog
-    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    // Escape first returns to the search results and then leaves search;
+    // the third one triggers the confirmation dialog
+    for _ in 0..3 {
+        harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    }
     harness.render().unwrap();
 
     // Dialog should show
@@ -315,8 +552,10 @@ fn test_confirmation_dialog_button_navigation() {
         .unwrap();
     harness.render().unwrap();
 
-    // Open confirmation dialog
-    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    // Back to search results, out of search, then open confirmation dialog
+    for _ in 0..3 {
+        harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    }
     harness.render().unwrap();
 
     // First button should be selected (Save and Exit has > indicator)
@@ -360,6 +599,56 @@ fn test_confirmation_dialog_button_navigation() {
         .unwrap();
 }
 
+/// Test that the confirmation dialog preselects the button chosen last time
+#[test]
+fn test_confirmation_dialog_remembers_last_choice() {
+    let mut harness = EditorTestHarness::new(100, 40).unwrap();
+
+    // Open settings, toggle a setting and ask to close
+    let toggle_and_close = |harness: &mut EditorTestHarness| {
+        harness
+            .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+            .unwrap();
+        harness
+            .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+            .unwrap();
+        harness.type_text("check").unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        for _ in 0..3 {
+            harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+        }
+        harness.render().unwrap();
+    };
+
+    toggle_and_close(&mut harness);
+    harness.assert_screen_contains(">[ Save and Exit ]");
+
+    // Choose Discard
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Unsaved Changes");
+
+    // Next time the dialog opens on Discard
+    toggle_and_close(&mut harness);
+    harness.assert_screen_contains("Unsaved Changes");
+    harness.assert_screen_contains(">[ Discard ]");
+    harness.assert_screen_not_contains(">[ Save and Exit ]");
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+}
+
 /// Test selection via keyboard navigation works
 /// Settings panel shows focus indicator ">" on focused item
 #[test]
@@ -665,6 +954,67 @@ fn test_settings_scrolling() {
     harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
 }
 
+/// Test that advanced settings are hidden until revealed with the toggle key
+#[test]
+fn test_settings_advanced_hidden_until_revealed() {
+    let mut harness = EditorTestHarness::new(100, 40).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    // Editor category, settings panel
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+
+    // "Highlight Context Bytes" is tagged x-advanced in the schema
+    let visible = |harness: &EditorTestHarness, name: &str| {
+        harness
+            .editor()
+            .settings_state()
+            .unwrap()
+            .current_page()
+            .unwrap()
+            .items
+            .iter()
+            .any(|item| item.name == name)
+    };
+    assert!(!visible(&harness, "Highlight Context Bytes"));
+    assert!(visible(&harness, "Line Numbers"));
+
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("highlight context").unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Highlight Context Bytes");
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+
+    // Reveal advanced settings
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert!(visible(&harness, "Highlight Context Bytes"));
+
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("highlight context").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("> Highlight Context Bytes");
+
+    // And hide them again
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Highlight Context Bytes");
+}
+
 /// Test scrollbar appears when there are many settings
 #[test]
 fn test_settings_scrollbar_visible() {
@@ -699,6 +1049,57 @@ fn test_settings_scrollbar_visible() {
     harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
 }
 
+/// Test that the settings scrollbar thumb follows the scroll position
+#[test]
+fn test_settings_scrollbar_tracks_position() {
+    let mut harness = EditorTestHarness::new(100, 25).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    // Editor category has enough settings to scroll
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(harness.scrollbar_ratio(), Some(0.0));
+
+    // Move down until the list is scrolled halfway
+    let scroll = |harness: &EditorTestHarness| {
+        let scroll = &harness
+            .editor()
+            .settings_state()
+            .unwrap()
+            .scroll_panel
+            .scroll;
+        (scroll.offset, scroll.max_offset())
+    };
+    while {
+        let (offset, max) = scroll(&harness);
+        offset * 2 < max
+    } {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    harness.render().unwrap();
+
+    let ratio = harness.scrollbar_ratio().expect("scrollbar should render");
+    assert!(
+        (0.3..=0.7).contains(&ratio),
+        "Thumb should be near the middle, got ratio {ratio}"
+    );
+
+    // At the bottom of the list the thumb reaches the end of the track
+    for _ in 0..100 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    harness.render().unwrap();
+    let (offset, max) = scroll(&harness);
+    assert_eq!(offset, max);
+    assert_eq!(harness.scrollbar_ratio(), Some(1.0));
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+}
+
 /// Test search jump scrolls to selected item
 #[test]
 fn test_settings_search_jump_scrolls() {
@@ -2712,6 +3113,50 @@ fn test_settings_edit_button_opens_config_file() {
     harness.assert_screen_contains("Editing User config");
 }
 
+/// Test that the footer shows the config file of the target layer and that
+/// the Edit action opens that file in the editor
+#[test]
+fn test_settings_footer_shows_config_path_and_opens_it() {
+    let mut harness = EditorTestHarness::new(100, 40).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    let path = harness
+        .editor()
+        .settings_state()
+        .unwrap()
+        .target_config_path()
+        .expect("User layer should have a config path")
+        .to_path_buf();
+    harness.assert_screen_contains(&path.display().to_string());
+
+    // Give the file known contents so we can recognize it once opened
+    let contents = "{\n  \"version\": 1,\n  \"editor\": {\n    \"tab_size\": 3\n  }\n}\n";
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, contents).unwrap();
+
+    // Footer -> Save, Cancel, Edit
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert!(!harness.editor().is_settings_open());
+    harness.assert_buffer_content(contents);
+    harness.assert_screen_contains("\"tab_size\": 3");
+}
+
 /// Test that Edit button is blocked when there are pending changes
 ///
 /// If the user has made changes in the Settings UI that haven't been saved,
@@ -2781,3 +3226,1677 @@ fn test_settings_edit_button_blocked_with_pending_changes() {
         .send_key(KeyCode::Enter, KeyModifiers::NONE)
         .unwrap();
 }
+
+// =============================================================================
+// MODAL SIZE PREFERENCE TESTS
+// =============================================================================
+
+/// Helper to set a numeric setting through the search + edit flow
+fn set_number_setting(harness: &mut EditorTestHarness, query: &str, value: &str) {
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text(query).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    // Enter editing mode, replace the value, confirm
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(value).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+}
+
+/// Test that the settings modal size is stored as a setting and restored on reopen
+#[test]
+fn test_settings_modal_size_persists_across_reopen() {
+    let mut harness = EditorTestHarness::new(100, 40).unwrap();
+
+    // Open settings at the default size (80% x 90% of 100x40 => 80x36 at (10, 2))
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.get_cell(10, 2).as_deref(), Some("┌"));
+
+    set_number_setting(&mut harness, "settings width", "60");
+    set_number_setting(&mut harness, "settings height", "50");
+    harness.assert_screen_contains("modified");
+
+    // Save and close
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert!(!harness.editor().is_settings_open());
+    assert_eq!(harness.config().editor.settings_width_percent, 60);
+    assert_eq!(harness.config().editor.settings_height_percent, 50);
+
+    // Reopen: 60% x 50% of 100x40 => 60x20 at (20, 10)
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(harness.get_cell(20, 10).as_deref(), Some("┌"));
+    assert_eq!(harness.get_cell(79, 10).as_deref(), Some("┐"));
+    assert_eq!(harness.get_cell(20, 29).as_deref(), Some("└"));
+    assert_eq!(harness.get_cell(79, 29).as_deref(), Some("┘"));
+    assert!(
+        harness.get_row_text(10).contains("Settings"),
+        "Modal title should be on the stored top row"
+    );
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+}
+
+/// Test that the modal size is kept within 10-100% and that a full-width
+/// dialog on a very wide terminal is sized without overflowing
+#[test]
+fn test_settings_modal_size_bounds_on_very_wide_terminal() {
+    let mut harness = EditorTestHarness::new(700, 40).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    set_number_setting(&mut harness, "settings width", "500");
+    set_number_setting(&mut harness, "settings height", "5");
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(harness.config().editor.settings_width_percent, 100);
+    assert_eq!(harness.config().editor.settings_height_percent, 10);
+
+    // 100% of 700 columns is capped at 100 columns: 100x4 at (300, 18)
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.get_cell(300, 18).as_deref(), Some("┌"));
+    assert_eq!(harness.get_cell(399, 18).as_deref(), Some("┐"));
+}
+
+/// Test that clicks are ignored while the go-to-path prompt is open
+#[test]
+fn test_settings_mouse_ignored_during_go_to_path() {
+    let mut harness = EditorTestHarness::new(100, 40).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    let screen = harness.screen_to_string();
+    let (row, col) = screen
+        .lines()
+        .enumerate()
+        .find_map(|(row, line)| {
+            let col = line.find("Terminal")?;
+            Some((row as u16, line[..col].chars().count() as u16))
+        })
+        .expect("Terminal category should be listed");
+
+    harness
+        .send_key(KeyCode::Char(':'), KeyModifiers::NONE)
+        .unwrap();
+    harness.mouse_click(col, row).unwrap();
+
+    let state = harness.editor().settings_state().unwrap();
+    assert!(state.go_to_path.is_some());
+    assert_eq!(state.current_page().unwrap().name, "General");
+
+    // With the prompt closed, the same click selects the category
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.mouse_click(col, row).unwrap();
+    let state = harness.editor().settings_state().unwrap();
+    assert_eq!(state.current_page().unwrap().name, "Terminal");
+}
+
+// =============================================================================
+// TWO-COLUMN LAYOUT TESTS
+// =============================================================================
+
+/// Test that consecutive toggles share a row on wide terminals and that
+/// Left/Right move between the two columns
+#[test]
+fn test_settings_two_column_toggles_on_wide_terminal() {
+    let mut harness = EditorTestHarness::new(140, 40).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("line wrap").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    let (_, numbers_row) = harness
+        .find_text_on_screen("Line Numbers")
+        .expect("Line Numbers should be visible");
+    // The focused setting's row; its name also appears in the breadcrumb
+    let (_, wrap_row) = harness
+        .find_text_on_screen("> Line Wrap")
+        .expect("Line Wrap should be visible");
+    assert_eq!(
+        numbers_row, wrap_row,
+        "Adjacent toggles should share a row on a wide terminal"
+    );
+    harness.assert_screen_contains("> Line Wrap");
+
+    // Left moves to the other column of the same row
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("> Line Numbers");
+    harness.assert_screen_not_contains("> Line Wrap");
+
+    // Right moves back
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("> Line Wrap");
+
+    // Clicking a column focuses that column's setting
+    let (x, y) = harness.find_text_on_screen("Line Numbers").unwrap();
+    harness.mouse_click(x, y).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("> Line Numbers");
+    let (x, y) = harness.find_text_on_screen("Line Wrap").unwrap();
+    harness.mouse_click(x, y).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("> Line Wrap");
+    harness.assert_screen_not_contains("> Line Numbers");
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+}
+
+/// Test that toggles fall back to one per row on terminals narrower than
+/// 120 columns, even when the settings panel itself is fairly wide
+#[test]
+fn test_settings_single_column_on_narrow_terminal() {
+    let mut harness = EditorTestHarness::new(119, 40).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("line wrap").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    let (_, numbers_row) = harness
+        .find_text_on_screen("Line Numbers")
+        .expect("Line Numbers should be visible");
+    // The focused setting's row; its name also appears in the breadcrumb
+    let (_, wrap_row) = harness
+        .find_text_on_screen("> Line Wrap")
+        .expect("Line Wrap should be visible");
+    assert_ne!(numbers_row, wrap_row);
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+}
+
+/// Test that Enter on a file-path setting opens the path picker and the
+/// chosen file is written back into the entry dialog
+#[test]
+fn test_settings_path_picker_sets_textmate_grammar() {
+    let mut harness = EditorTestHarness::with_temp_project(100, 40).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    std::fs::create_dir(project_dir.join("grammars")).unwrap();
+    std::fs::write(project_dir.join("grammars/custom.json"), "{}").unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    // Open a language entry dialog
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    for _ in 0..10 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Edit Value");
+
+    let dialog_item_name = |harness: &EditorTestHarness| {
+        harness
+            .editor()
+            .settings_state()
+            .and_then(|s| s.entry_dialog())
+            .and_then(|d| d.current_item())
+            .map(|item| item.name.clone())
+    };
+
+    // Focus the Textmate Grammar field
+    for _ in 0..30 {
+        if dialog_item_name(&harness).as_deref() == Some("Textmate Grammar") {
+            break;
+        }
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    assert_eq!(
+        dialog_item_name(&harness).as_deref(),
+        Some("Textmate Grammar")
+    );
+    let key = harness
+        .editor()
+        .settings_state()
+        .and_then(|s| s.entry_dialog())
+        .map(|d| d.get_key())
+        .unwrap();
+
+    // Enter opens the picker in the working directory
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Select File");
+    harness.assert_screen_contains("grammars/");
+
+    // Tab-complete into the directory, then pick the file
+    harness
+        .send_key(KeyCode::Char('g'), KeyModifiers::NONE)
+        .unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("custom.json");
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Select File");
+
+    let expected = project_dir
+        .join("grammars/custom.json")
+        .to_string_lossy()
+        .into_owned();
+
+    // Save the entry dialog via its Save button
+    for _ in 0..30 {
+        let on_save = harness
+            .editor()
+            .settings_state()
+            .and_then(|s| s.entry_dialog())
+            .is_some_and(|d| d.focus_on_buttons && d.focused_button == 0);
+        if on_save {
+            break;
+        }
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    let entry_path = format!("/languages/{}", key);
+    let change = harness
+        .pending_changes()
+        .into_iter()
+        .find(|(path, _, _)| *path == entry_path)
+        .expect("language entry should be pending");
+    assert_eq!(
+        change.2["textmate_grammar"],
+        serde_json::Value::String(expected)
+    );
+}
+
+/// Test that Ctrl+R replaces a substring across setting values and stages
+/// every affected setting as modified
+#[test]
+fn test_settings_replace_in_values() {
+    let mut config = fresh::config::Config::default();
+    config.file_explorer.custom_ignore_patterns = vec!["/opt/old/build".to_string()];
+    config.lsp.get_mut("rust").unwrap().command = "/opt/old/bin/rust-analyzer".to_string();
+    let mut harness = EditorTestHarness::with_config(100, 40, config).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('r'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("/opt/old").unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Find in values: /opt/old");
+    harness.assert_screen_contains("2 matching settings");
+
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.type_text("/opt/new").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Find in values");
+
+    assert_eq!(
+        harness.get_setting("/file_explorer/custom_ignore_patterns"),
+        Some(serde_json::json!(["/opt/new/build"]))
+    );
+    let rust_lsp = harness.get_setting("/lsp/rust").unwrap();
+    assert_eq!(rust_lsp["command"], "/opt/new/bin/rust-analyzer");
+    let changed: Vec<String> = harness
+        .pending_changes()
+        .into_iter()
+        .map(|(path, _, _)| path)
+        .collect();
+    assert_eq!(
+        changed,
+        vec!["/file_explorer/custom_ignore_patterns", "/lsp/rust"]
+    );
+
+    let state = harness.editor().settings_state().unwrap();
+    for path in ["/file_explorer/custom_ignore_patterns", "/lsp"] {
+        let item = state
+            .pages
+            .iter()
+            .flat_map(|page| &page.items)
+            .find(|item| item.path == path)
+            .unwrap();
+        assert!(item.modified, "{} should be marked modified", path);
+    }
+}
+
+/// Test that the footer shows how long edits have been unsaved, and when
+/// settings were last saved
+#[test]
+fn test_settings_footer_shows_modified_age() {
+    let mut harness = EditorTestHarness::new(100, 40).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Modified");
+
+    // Toggle a setting, then let time pass
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("check").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.advance_time(std::time::Duration::from_secs(42));
+    harness.render().unwrap();
+    harness.assert_screen_contains("Modified 42 seconds ago");
+
+    harness.advance_time(std::time::Duration::from_secs(10 * 60));
+    harness.render().unwrap();
+    harness.assert_screen_contains("Modified 10 minutes ago");
+
+    // After saving, reopening shows the save time instead
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.advance_time(std::time::Duration::from_secs(5));
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Saved 5 seconds ago");
+    harness.assert_screen_not_contains("Modified");
+}
+
+/// Test that settings introduced after the last-seen version get a NEW
+/// badge, which goes away once settings are saved from this version
+#[test]
+fn test_settings_new_badge_for_recent_settings() {
+    let config = fresh::config::Config {
+        settings_last_seen_version: Some("0.1.0".to_string()),
+        ..Default::default()
+    };
+    let mut harness = EditorTestHarness::with_config(100, 40, config).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("settings width").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    let screen = harness.screen_to_string();
+    let row = screen
+        .lines()
+        .find(|line| line.contains("Settings Width Percent") && !line.contains("›"))
+        .expect("Settings Width Percent should be listed");
+    assert!(row.contains("NEW"), "row should show a NEW badge: {}", row);
+    let tab_row = screen.lines().find(|line| line.contains("Tab Size"));
+    assert!(tab_row.is_none_or(|line| !line.contains("NEW")));
+
+    // Saving records the current version, so nothing is new afterwards
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(
+        harness
+            .editor()
+            .config()
+            .settings_last_seen_version
+            .as_deref(),
+        Some(env!("CARGO_PKG_VERSION"))
+    );
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("settings width").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("NEW");
+}
+
+/// Test that the last-seen version is recorded in the User layer even when
+/// saving to the Project layer
+#[test]
+fn test_settings_last_seen_version_saved_to_user_layer() {
+    let mut harness =
+        EditorTestHarness::with_temp_project_and_config(100, 40, Default::default()).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let project_config = project_dir.join(".fresh/config.json");
+    let user_config = project_dir.parent().unwrap().join("config/config.json");
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(":editor.line_numbers").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    // Footer Layer button: User -> Project. Switching layers drops the
+    // pending change, so toggle again afterwards.
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text(":editor.line_numbers").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Settings [Project]");
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+
+    let project: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&project_config).unwrap()).unwrap();
+    assert_eq!(project["editor"]["line_numbers"], serde_json::json!(false));
+    assert!(project.get("settings_last_seen_version").is_none());
+
+    let user: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&user_config).unwrap()).unwrap();
+    assert_eq!(
+        user["settings_last_seen_version"],
+        serde_json::json!(env!("CARGO_PKG_VERSION"))
+    );
+}
+
+/// Test that `g` shows a pending-chord hint and `gg` / `G` jump to the
+/// first and last setting
+#[test]
+fn test_settings_chord_hint_and_jumps() {
+    let mut harness = EditorTestHarness::new(100, 40).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    for _ in 0..3 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("g-");
+
+    let selected = |harness: &EditorTestHarness| {
+        let state = harness.editor().settings_state().unwrap();
+        (
+            state.selected_item,
+            state.current_page().map_or(0, |p| p.items.len()),
+        )
+    };
+    assert_ne!(selected(&harness).0, 0);
+
+    // First key of the chord shows the pending prefix
+    harness
+        .send_key(KeyCode::Char('g'), KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("g-");
+
+    harness
+        .send_key(KeyCode::Char('g'), KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("g-");
+    assert_eq!(selected(&harness).0, 0);
+
+    harness
+        .send_key(KeyCode::Char('G'), KeyModifiers::SHIFT)
+        .unwrap();
+    let (item, count) = selected(&harness);
+    assert_eq!(item, count - 1);
+
+    // The help overlay lists the chords
+    harness
+        .send_key(KeyCode::Char('?'), KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Go to first item");
+    harness.assert_screen_contains("Go to last item");
+}
+
+/// Test that a key which does not complete `g` is handled as usual
+#[test]
+fn test_settings_pending_g_passes_other_keys_through() {
+    let mut harness = EditorTestHarness::new(100, 40).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    let selected =
+        |harness: &EditorTestHarness| harness.editor().settings_state().unwrap().selected_item;
+    assert_eq!(selected(&harness), 0);
+
+    harness
+        .send_key(KeyCode::Char('g'), KeyModifiers::NONE)
+        .unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(selected(&harness), 1);
+    harness.assert_screen_not_contains("g-");
+
+    harness
+        .send_key(KeyCode::Char('g'), KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    assert!(harness.editor().settings_state().unwrap().search_active);
+}
+
+/// Test starring a setting lists it in the Starred category, which is
+/// saved right away rather than as an unsaved change
+#[test]
+fn test_settings_star_setting_shows_in_starred_category() {
+    let mut harness = EditorTestHarness::new(100, 40).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Starred");
+
+    // Jump to a setting via search and star it
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("check").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    let path = harness
+        .editor()
+        .settings_state()
+        .unwrap()
+        .current_item()
+        .unwrap()
+        .path
+        .clone();
+    harness
+        .send_key(KeyCode::Char('*'), KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Starred");
+    harness.assert_screen_contains("★");
+
+    // The focused setting stays selected on its own page
+    let state = harness.editor().settings_state().unwrap();
+    assert_eq!(state.current_item().unwrap().path, path);
+    assert_ne!(state.current_page().unwrap().name, "Starred");
+
+    // The star is saved at once and is not an unsaved change
+    assert!(!state.has_changes());
+    assert_eq!(
+        harness.editor().config().starred_settings,
+        vec![path.clone()]
+    );
+
+    // Close without a prompt and reopen: the Starred category comes first
+    // and lists the setting
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    assert!(!harness.editor().is_settings_open());
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+
+    let state = harness.editor().settings_state().unwrap();
+    let page = state.current_page().unwrap();
+    assert_eq!(page.name, "Starred");
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(state.current_item().unwrap().path, path);
+    harness.assert_screen_contains("Check For Updates");
+
+    // Switching the target layer with the footer Layer button keeps the
+    // Starred category
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Settings [Project]");
+    let state = harness.editor().settings_state().unwrap();
+    assert_eq!(state.current_page().unwrap().name, "Starred");
+
+    // Back through the footer buttons to the setting
+    for _ in 0..6 {
+        harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    }
+    assert_eq!(
+        harness.editor().settings_state().unwrap().focus_panel,
+        FocusPanel::Settings
+    );
+
+    // Unstarring removes the category again
+    harness
+        .send_key(KeyCode::Char('*'), KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Starred");
+}
+
+/// Test that a boolean setting shows a bold check mark that flips
+/// between the off `[ ]` and on `[x]` glyphs
+#[test]
+fn test_settings_boolean_toggle_glyph() {
+    let mut harness = EditorTestHarness::new(100, 40).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("check for updates").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    // The glyph on the setting's own row, and the column of its mark
+    let glyph = |harness: &EditorTestHarness| {
+        let (_, y) = harness.find_text_on_screen("> Check For Updates").unwrap();
+        let row = harness.get_screen_row(y as usize);
+        let start = row.find(": [").expect("toggle glyph on the row") + 2;
+        let glyph: String = row[start..].chars().take(3).collect();
+        let mark_x = row[..start].chars().count() as u16 + 1;
+        (glyph, mark_x, y)
+    };
+
+    let (off, _, _) = glyph(&harness);
+    assert_eq!(off, "[ ]");
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    let (on, mark_x, y) = glyph(&harness);
+    assert_eq!(on, "[x]");
+    let style = harness.get_cell_style(mark_x, y).unwrap();
+    assert!(style.add_modifier.contains(Modifier::BOLD));
+}
+
+/// Test that Tab while searching also matches current values, and the
+/// result shows the value that matched
+#[test]
+fn test_settings_search_by_value() {
+    let mut harness = EditorTestHarness::new(100, 40).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("high-contrast").unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("No results found");
+    harness.assert_screen_contains("Tab: also values");
+
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Names and values");
+    harness.assert_screen_contains("Value: high-contrast");
+
+    let state = harness.editor().settings_state().unwrap();
+    let first = state.current_search_result().unwrap();
+    assert_eq!(first.item.path, "/theme");
+
+    // Jumping to the result selects the setting
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    let state = harness.editor().settings_state().unwrap();
+    assert_eq!(state.current_item().unwrap().path, "/theme");
+}
+
+/// Test that the focused setting's breadcrumb replaces the page title,
+/// naming its home category even when shown on the Starred page
+#[test]
+fn test_settings_breadcrumb_header() {
+    let mut harness = EditorTestHarness::new(100, 40).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("›");
+
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("tab size").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Editor › Tab Size");
+
+    // Star it and look at it from the Starred page, tabbing through the
+    // footer buttons back to the categories
+    harness
+        .send_key(KeyCode::Char('*'), KeyModifiers::NONE)
+        .unwrap();
+    // Settings -> Save, Cancel, Edit buttons -> categories
+    for _ in 0..4 {
+        harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    }
+    assert_eq!(
+        harness.editor().settings_state().unwrap().focus_panel,
+        FocusPanel::Categories
+    );
+    harness
+        .send_key(KeyCode::Char('g'), KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('g'), KeyModifiers::NONE)
+        .unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(
+        harness
+            .editor()
+            .settings_state()
+            .unwrap()
+            .current_page()
+            .unwrap()
+            .name,
+        "Starred"
+    );
+    harness.assert_screen_contains("Editor › Tab Size");
+}
+
+/// Test that a project toggle cycles on, off and inherit, and that saving
+/// it in the inherit state drops the project override
+#[test]
+fn test_settings_toggle_marks_inherited_value() {
+    let mut harness =
+        EditorTestHarness::with_temp_project_and_config(100, 40, Default::default()).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let user_config = project_dir.parent().unwrap().join("config/config.json");
+    std::fs::create_dir_all(user_config.parent().unwrap()).unwrap();
+    std::fs::write(&user_config, r#"{"editor": {"line_numbers": false}}"#).unwrap();
+    let project_config = project_dir.join(".fresh/config.json");
+    std::fs::create_dir_all(project_config.parent().unwrap()).unwrap();
+    std::fs::write(&project_config, r#"{"editor": {"line_numbers": true}}"#).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    assert!(
+        !harness
+            .editor()
+            .settings_state()
+            .unwrap()
+            .showing_customizations
+    );
+
+    // Footer Layer button: User -> Project
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    // Through the other four footer buttons back to the categories
+    for _ in 0..5 {
+        harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    }
+    assert_eq!(
+        harness.editor().settings_state().unwrap().focus_panel,
+        FocusPanel::Categories
+    );
+    harness.render().unwrap();
+    harness.assert_screen_contains("Settings [Project]");
+
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("line numbers").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    let row = |harness: &EditorTestHarness| {
+        let (_, y) = harness.find_text_on_screen("> Line Numbers").unwrap();
+        harness.get_screen_row(y as usize)
+    };
+    harness.render().unwrap();
+    assert!(row(&harness).contains("[x]"));
+    assert!(!row(&harness).contains("(inherited)"));
+
+    // On -> off -> inherit, which shows what the user layer gives it
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert!(row(&harness).contains("[ ]"));
+    assert!(!row(&harness).contains("(inherited)"));
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert!(row(&harness).contains("[ ] (inherited)"));
+
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert!(!harness.editor().config().editor.line_numbers);
+    let saved = std::fs::read_to_string(&project_config).unwrap();
+    assert!(!saved.contains("line_numbers"), "override kept: {}", saved);
+}
+
+/// Test that a user toggle cycled to inherit takes the default again once
+/// saved, and that an explicit value equal to the default is kept
+#[test]
+fn test_settings_toggle_inherit_in_user_layer() {
+    let mut config = fresh::config::Config::default();
+    config.editor.line_numbers = false;
+    let mut harness = EditorTestHarness::with_temp_project_and_config(100, 40, config).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let user_config = project_dir.parent().unwrap().join("config/config.json");
+    std::fs::create_dir_all(user_config.parent().unwrap()).unwrap();
+    std::fs::write(&user_config, r#"{"editor": {"line_numbers": false}}"#).unwrap();
+
+    let open_line_numbers = |harness: &mut EditorTestHarness| {
+        harness
+            .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+            .unwrap();
+        let state = harness.editor().settings_state().unwrap();
+        if state.showing_customizations {
+            harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+        }
+        harness
+            .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+            .unwrap();
+        harness.type_text("line numbers").unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        harness.render().unwrap();
+    };
+    let row = |harness: &EditorTestHarness| {
+        let (_, y) = harness.find_text_on_screen("> Line Numbers").unwrap();
+        harness.get_screen_row(y as usize)
+    };
+
+    open_line_numbers(&mut harness);
+    harness.assert_screen_contains("Settings [User]");
+    assert!(row(&harness).contains("[ ]"));
+    assert!(!row(&harness).contains("(inherited)"));
+
+    // Off -> on -> inherit, which shows the default
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert!(row(&harness).contains("[x]"));
+    assert!(!row(&harness).contains("(inherited)"));
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert!(row(&harness).contains("[x] (inherited)"));
+
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert!(harness.editor().config().editor.line_numbers);
+    let saved = std::fs::read_to_string(&user_config).unwrap();
+    assert!(!saved.contains("line_numbers"), "override kept: {}", saved);
+
+    // Inherit -> off -> on sets the default explicitly, and saving keeps it
+    open_line_numbers(&mut harness);
+    assert!(row(&harness).contains("[x] (inherited)"));
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert!(row(&harness).contains("[ ]"));
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert!(row(&harness).contains("[x]"));
+    assert!(!row(&harness).contains("(inherited)"));
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    let saved: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&user_config).unwrap()).unwrap();
+    assert_eq!(saved["editor"]["line_numbers"], serde_json::json!(true));
+}
+
+/// Test that the unsaved changes dialog lists each change as
+/// "path: old → new" with the old value in red and the new one in green
+#[test]
+fn test_settings_confirm_dialog_shows_old_and_new_values() {
+    let mut harness = EditorTestHarness::new(100, 40).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("check for updates").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    for _ in 0..3 {
+        harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    }
+    harness.render().unwrap();
+    harness.assert_screen_contains("Unsaved Changes");
+
+    let (x, y) = harness
+        .find_text_on_screen("/check_for_updates: ")
+        .expect("change listed in the dialog");
+    let row = harness.get_screen_row(y as usize);
+    assert!(
+        row.contains("/check_for_updates: false → true"),
+        "row: {}",
+        row
+    );
+
+    let old_x = x + "/check_for_updates: ".len() as u16;
+    let new_x = old_x + "false → ".chars().count() as u16;
+    let theme = harness.editor().theme().clone();
+    let old_style = harness.get_cell_style(old_x, y).unwrap();
+    let new_style = harness.get_cell_style(new_x, y).unwrap();
+    assert_eq!(old_style.fg, Some(theme.diagnostic_error_fg));
+    assert_eq!(new_style.fg, Some(theme.diagnostic_hint_fg));
+    assert_ne!(old_style.fg, new_style.fg);
+}
+
+/// Test that Alt+Up/Alt+Down reorder the items of a list-valued setting
+/// and stage the new order as a modified change
+#[test]
+fn test_settings_reorder_list_items() {
+    let mut harness = EditorTestHarness::new(100, 40).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("custom ignore").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    // Start editing and add two items
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    for pattern in ["*.log", "target"] {
+        harness.type_text(pattern).unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.render().unwrap();
+
+    // Focus the last item and move it to the top
+    harness.send_key(KeyCode::Up, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Up, KeyModifiers::ALT).unwrap();
+    harness.render().unwrap();
+
+    let path = "/file_explorer/custom_ignore_patterns";
+    let staged = serde_json::json!(["target", "*.log"]);
+    assert_eq!(harness.get_setting(path), Some(staged.clone()));
+    let changes = harness.pending_changes();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].0, path);
+    assert_eq!(changes[0].2, staged);
+
+    let screen = harness.screen_to_string();
+    let target_row = screen.lines().position(|l| l.contains("target")).unwrap();
+    let log_row = screen.lines().position(|l| l.contains("*.log")).unwrap();
+    assert!(target_row < log_row, "screen:\n{}", screen);
+    harness.assert_screen_contains("● File Explorer");
+}
+
+/// Test that the first time settings are opened, a panel lists exactly the
+/// settings the config files set to non-default values
+#[test]
+fn test_settings_first_open_lists_customized_settings() {
+    let config = fresh::config::Config {
+        editor: fresh::config::EditorConfig {
+            tab_size: 2,
+            line_numbers: false,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut harness = EditorTestHarness::with_temp_project_and_config(100, 40, config).unwrap();
+    let project_config = harness.project_dir().unwrap().join(".fresh/config.json");
+    std::fs::create_dir_all(project_config.parent().unwrap()).unwrap();
+    std::fs::write(
+        &project_config,
+        r#"{"editor": {"tab_size": 2, "line_numbers": false}}"#,
+    )
+    .unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("What Changed");
+
+    let state = harness.editor().settings_state().unwrap();
+    let paths: Vec<_> = state
+        .customized_settings()
+        .into_iter()
+        .map(|c| c.path)
+        .collect();
+    assert_eq!(paths, vec!["/editor/line_numbers", "/editor/tab_size"]);
+    harness.assert_screen_contains("/editor/line_numbers: true → false");
+    harness.assert_screen_contains("/editor/tab_size: 4 → 2");
+    harness.assert_screen_not_contains("/check_for_updates");
+
+    // Any key dismisses the panel, and it stays closed on reopening
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("What Changed");
+    harness.assert_screen_contains("Settings");
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("What Changed");
+
+    // A later session sharing the same data directory doesn't show it either
+    let project_dir = harness.project_dir().unwrap();
+    let dir_context =
+        fresh::config_io::DirectoryContext::for_testing(project_dir.parent().unwrap());
+    let mut next_session = EditorTestHarness::with_shared_dir_context(
+        100,
+        40,
+        harness.editor().config().clone(),
+        project_dir,
+        dir_context,
+    )
+    .unwrap();
+    next_session
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    next_session.render().unwrap();
+    next_session.assert_screen_not_contains("What Changed");
+    next_session.assert_screen_contains("Settings");
+}
+
+/// Test that the page title row shows the focused setting's position out
+/// of the page's item count, updating as the focus moves
+#[test]
+fn test_settings_item_position_status() {
+    let mut harness = EditorTestHarness::new(100, 40).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+
+    let total = harness
+        .editor()
+        .settings_state()
+        .unwrap()
+        .current_page()
+        .unwrap()
+        .items
+        .len();
+    assert!(total > 4, "page should have enough items to navigate");
+    harness.assert_screen_contains(&format!("1/{}", total));
+
+    for _ in 0..3 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    harness.render().unwrap();
+    harness.assert_screen_contains(&format!("4/{}", total));
+    harness.assert_screen_not_contains(&format!("1/{}", total));
+}
+
+/// Test that pressing `o` on a setting with a documentation link shows the
+/// link in a notification
+#[test]
+fn test_settings_show_doc_url() {
+    let mut harness = EditorTestHarness::new(160, 40).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("lsp").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    let state = harness.editor().settings_state().unwrap();
+    assert_eq!(state.current_item().unwrap().path, "/lsp");
+
+    harness
+        .send_key(KeyCode::Char('o'), KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains(
+        "Docs: https://github.com/sinelaw/fresh/blob/master/docs/USER_GUIDE.md#lsp-integration",
+    );
+
+    // Once expired, the notification asks for a redraw without any input
+    harness.advance_time(std::time::Duration::from_secs(5));
+    assert!(harness.editor_mut().process_async_messages());
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Docs: https://github.com");
+}
+
+/// Test that `R R` stages the default for every setting the target layer
+/// can reset, that it reports the ones a higher layer still sets, and that
+/// a single `R` followed by another key does nothing
+#[test]
+fn test_settings_reset_all_to_defaults() {
+    let config = fresh::config::Config {
+        editor: fresh::config::EditorConfig {
+            tab_size: 2,
+            line_numbers: false,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut harness = EditorTestHarness::with_temp_project_and_config(100, 40, config).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let user_config = project_dir.parent().unwrap().join("config/config.json");
+    std::fs::create_dir_all(user_config.parent().unwrap()).unwrap();
+    std::fs::write(&user_config, r#"{"editor": {"tab_size": 2}}"#).unwrap();
+    let project_config = project_dir.join(".fresh/config.json");
+    std::fs::create_dir_all(project_config.parent().unwrap()).unwrap();
+    std::fs::write(&project_config, r#"{"editor": {"line_numbers": false}}"#).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    // Dismiss the "what changed" panel
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+
+    // A single R asks for confirmation; any other key cancels
+    harness
+        .send_key(KeyCode::Char('R'), KeyModifiers::SHIFT)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Press R again");
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Press R again");
+    assert!(!harness.editor().settings_state().unwrap().has_changes());
+
+    harness
+        .send_key(KeyCode::Char('R'), KeyModifiers::SHIFT)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('R'), KeyModifiers::SHIFT)
+        .unwrap();
+    harness.render().unwrap();
+    // The project layer's line_numbers wins over anything the user layer says
+    harness.assert_screen_contains("Reset 1 setting to its default; 1 still set by a higher layer");
+
+    let state = harness.editor().settings_state().unwrap();
+    assert_eq!(state.pending_changes.len(), 1);
+    assert_eq!(
+        state.pending_changes["/editor/tab_size"],
+        serde_json::json!(4)
+    );
+}
+
+/// Test that the `R` reset prompt expires with its notification, so a
+/// later `R` asks again instead of resetting
+#[test]
+fn test_settings_reset_prompt_expires() {
+    let config = fresh::config::Config {
+        editor: fresh::config::EditorConfig {
+            tab_size: 2,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let mut harness = EditorTestHarness::with_temp_project_and_config(100, 40, config).unwrap();
+    let user_config = harness
+        .project_dir()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .join("config/config.json");
+    std::fs::create_dir_all(user_config.parent().unwrap()).unwrap();
+    std::fs::write(&user_config, r#"{"editor": {"tab_size": 2}}"#).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    // Dismiss the "what changed" panel
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+
+    harness
+        .send_key(KeyCode::Char('R'), KeyModifiers::SHIFT)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Press R again");
+
+    harness.advance_time(std::time::Duration::from_secs(5));
+    harness.editor_mut().process_async_messages();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Press R again");
+
+    // This R starts a new prompt rather than answering the expired one
+    harness
+        .send_key(KeyCode::Char('R'), KeyModifiers::SHIFT)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Press R again");
+    assert!(!harness.editor().settings_state().unwrap().has_changes());
+}
+
+/// Test rebinding an action on the Keybindings page: the next key pressed
+/// on a focused action becomes its binding, staged as a keybindings change
+/// that also unbinds the keymap's key
+#[test]
+fn test_settings_rebind_action_key() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let file = temp_dir.path().join("notes.txt");
+    std::fs::write(&file, "hello").unwrap();
+    let mut harness = EditorTestHarness::new(120, 40).unwrap();
+    harness.open_file(&file).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("save").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    let item = harness
+        .editor()
+        .settings_state()
+        .unwrap()
+        .current_item()
+        .unwrap()
+        .clone();
+    assert_eq!(item.path, "/keybindings/save");
+    assert!(!item.modified);
+    harness.assert_screen_contains("Save: Ctrl+S");
+
+    // Enter waits for the new key, which even Ctrl+S doesn't escape
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Press a key");
+
+    // Alt+F opens the File menu from the global context, an action with
+    // arguments that has no row of its own
+    harness
+        .send_key(KeyCode::Char('f'), KeyModifiers::ALT)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Alt+F is also bound to Menu Open");
+    harness.assert_screen_contains("⚠ also bound to Menu Open");
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(
+            KeyCode::Char('s'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT,
+        )
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Press a key");
+    harness.assert_screen_contains("• Save: Ctrl+Alt+S");
+    harness.assert_screen_not_contains("⚠ also bound");
+
+    let state = harness.editor().settings_state().unwrap();
+    assert!(state.visible);
+    assert!(state.current_item().unwrap().modified);
+    assert_eq!(
+        state.pending_changes["/keybindings"],
+        serde_json::json!([
+            {
+                "key": "s",
+                "modifiers": ["ctrl"],
+                "action": "noop",
+                "args": {},
+                "when": "normal"
+            },
+            {
+                "key": "s",
+                "modifiers": ["ctrl", "alt"],
+                "action": "save",
+                "args": {},
+                "when": "normal"
+            }
+        ])
+    );
+
+    // Keybinding rows can't be starred
+    harness
+        .send_key(KeyCode::Char('*'), KeyModifiers::NONE)
+        .unwrap();
+    assert!(harness
+        .editor()
+        .settings_state()
+        .unwrap()
+        .starred_paths()
+        .is_empty());
+
+    // Once saved, Ctrl+S no longer saves the file and Ctrl+Alt+S does
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert!(!harness.editor().is_settings_open());
+    harness.type_text("X").unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(std::fs::read_to_string(&file).unwrap(), "hello");
+    harness
+        .send_key(
+            KeyCode::Char('s'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT,
+        )
+        .unwrap();
+    assert_eq!(std::fs::read_to_string(&file).unwrap(), "Xhello");
+}
+
+/// Test typing a size with a unit suffix: an unknown unit is rejected
+/// inline, and a valid one is normalized to bytes and shown with its unit
+#[test]
+fn test_settings_number_unit_suffix() {
+    let mut harness = EditorTestHarness::new(160, 40).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    // The size settings are advanced
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('/'), KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("large file threshold").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(
+        harness
+            .editor()
+            .settings_state()
+            .unwrap()
+            .current_item()
+            .unwrap()
+            .path,
+        "/editor/large_file_threshold_bytes"
+    );
+    harness.assert_screen_contains("1mib");
+
+    // Enter starts editing with the value selected
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("512xb").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("unknown unit \"xb\"");
+    let state = harness.editor().settings_state().unwrap();
+    assert!(state.is_number_editing());
+    assert!(!state.has_changes());
+
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("512kb").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("unknown unit");
+    harness.assert_screen_contains("[512kb]");
+
+    let state = harness.editor().settings_state().unwrap();
+    assert!(!state.is_number_editing());
+    assert_eq!(
+        state.pending_changes["/editor/large_file_threshold_bytes"],
+        serde_json::json!(512_000)
+    );
+}
+
+/// Test jumping to a setting by typing its path after `:`, with Tab
+/// completing the path
+#[test]
+fn test_settings_go_to_path() {
+    let mut harness = EditorTestHarness::new(120, 40).unwrap();
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(":check_for_updates").unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Go to: check_for_updates");
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Go to:");
+
+    let state = harness.editor().settings_state().unwrap();
+    assert_eq!(state.focus_panel, FocusPanel::Settings);
+    assert_eq!(state.current_item().unwrap().path, "/check_for_updates");
+
+    // Tab completes a nested path
+    harness.type_text(":editor.tab_si").unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    assert_eq!(
+        harness
+            .editor()
+            .settings_state()
+            .unwrap()
+            .go_to_path
+            .as_deref(),
+        Some("editor.tab_size")
+    );
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    let state = harness.editor().settings_state().unwrap();
+    assert_eq!(state.current_item().unwrap().path, "/editor/tab_size");
+
+    // An unknown path stays put and says so
+    harness.type_text(":no_such_setting").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("No setting at no_such_setting");
+    let state = harness.editor().settings_state().unwrap();
+    assert_eq!(state.current_item().unwrap().path, "/editor/tab_size");
+}
+
+/// Test previewing staged layout changes with Ctrl+P: the editor's line
+/// numbers and the dialog size change live, Esc reverts them while keeping
+/// the changes pending, and Enter keeps them
+#[test]
+fn test_settings_preview_and_revert_layout() {
+    let mut harness = EditorTestHarness::new(120, 40).unwrap();
+    // The editor gutter left of the dialog, on the first buffer line
+    let gutter = |harness: &EditorTestHarness| -> String {
+        harness.get_row_text(2).chars().take(8).collect()
+    };
+    harness.render().unwrap();
+    assert!(gutter(&harness).contains("1 │"));
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(":editor.settings_width_percent").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("50").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text(":editor.line_numbers").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    // Staged only: the dialog is still 80% of 120 columns wide and the
+    // editor still shows line numbers
+    let state = harness.editor().settings_state().unwrap();
+    assert_eq!(
+        state.pending_changes["/editor/settings_width_percent"],
+        serde_json::json!(50)
+    );
+    assert_eq!(
+        state.pending_changes["/editor/line_numbers"],
+        serde_json::json!(false)
+    );
+    assert_eq!(harness.get_cell(12, 2).as_deref(), Some("┌"));
+    assert!(gutter(&harness).contains("1 │"));
+
+    // Preview applies them: the dialog shrinks to 60 columns and the
+    // gutter goes
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.get_cell(30, 2).as_deref(), Some("┌"));
+    assert_ne!(harness.get_cell(12, 2).as_deref(), Some("┌"));
+    assert!(!gutter(&harness).contains("1 │"));
+    harness.assert_screen_contains("(previewing)");
+    assert_eq!(harness.editor().config().editor.settings_width_percent, 50);
+
+    // Reverting restores the layout and leaves the changes pending
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.get_cell(12, 2).as_deref(), Some("┌"));
+    assert!(gutter(&harness).contains("1 │"));
+    harness.assert_screen_not_contains("(previewing)");
+    assert_eq!(harness.editor().config().editor.settings_width_percent, 80);
+    let state = harness.editor().settings_state().unwrap();
+    assert!(!state.previewing);
+    assert_eq!(
+        state.pending_changes["/editor/settings_width_percent"],
+        serde_json::json!(50)
+    );
+
+    // Keeping a preview saves it and the layout stays
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert!(!harness.editor().is_settings_open());
+    assert!(!harness.config().editor.line_numbers);
+    assert!(!gutter(&harness).contains("1 │"));
+}
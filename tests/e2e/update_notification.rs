@@ -1,6 +1,7 @@
 //! E2E tests for the update notification UI
 
 use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
 use fresh::services::release_checker::{
     start_periodic_update_check_with_interval, CURRENT_VERSION,
 };
@@ -208,3 +209,42 @@ fn test_update_notification_positioned_near_ctrl_p() {
     drop(harness);
     let _ = stop_tx.send(());
 }
+
+#[test]
+fn test_settings_update_check_shows_notification() {
+    let (stop_tx, url) = start_mock_release_server(CURRENT_VERSION);
+
+    let mut harness = EditorTestHarness::new(120, 40).unwrap();
+    harness.editor_mut().set_releases_url(url);
+
+    harness
+        .send_key(KeyCode::Char(','), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('u'), KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Checking for updates");
+
+    // Wait for the background check against the mock server
+    let start = Instant::now();
+    let mut finished = false;
+    while start.elapsed() < Duration::from_secs(5) {
+        harness.process_async_and_render().unwrap();
+        if harness.screen_to_string().contains("Up to date") {
+            finished = true;
+            break;
+        }
+        thread::sleep(Duration::from_millis(20));
+    }
+    assert!(finished, "Update check result was not shown in settings");
+    harness.assert_screen_not_contains("Checking for updates");
+
+    // The result fades after a few seconds
+    harness.advance_time(Duration::from_secs(10));
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Up to date");
+
+    drop(harness);
+    let _ = stop_tx.send(());
+}